@@ -1,6 +1,10 @@
-use crate::{Slice, SliceBorrowed, SliceMut, SliceOwned, Unique};
+use crate::{
+    ContiguousBorrowed, ContiguousMut, RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned,
+    SliceRawMut, Unique,
+};
 
 /// Two chained slices; see [`Slice::chain`].
+#[derive(Clone, Copy)]
 pub struct Chain<S1, S2>(pub S1, pub S2);
 
 impl<S1, S2> Slice for Chain<S1, S2>
@@ -15,9 +19,12 @@ where
     }
 
     fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
-        self.0
-            .get_with(index, f)
-            .or_else(|| self.1.get_with(index - self.0.len(), f))
+        let offset = self.0.len();
+        if index < offset {
+            self.0.get_with(index, f)
+        } else {
+            self.1.get_with(index - offset, f)
+        }
     }
 }
 
@@ -27,9 +34,12 @@ where
     S2: SliceOwned<Output = S1::Output>,
 {
     fn get_owned(&self, index: usize) -> Option<Self::Output> {
-        self.0
-            .get_owned(index)
-            .or_else(|| self.1.get_owned(index - self.0.len()))
+        let offset = self.0.len();
+        if index < offset {
+            self.0.get_owned(index)
+        } else {
+            self.1.get_owned(index - offset)
+        }
     }
 }
 
@@ -39,9 +49,12 @@ where
     S2: SliceBorrowed<Output = S1::Output>,
 {
     fn get(&self, index: usize) -> Option<&Self::Output> {
-        self.0
-            .get(index)
-            .or_else(|| self.1.get(index - self.0.len()))
+        let offset = self.0.len();
+        if index < offset {
+            self.0.get(index)
+        } else {
+            self.1.get(index - offset)
+        }
     }
 }
 
@@ -52,8 +65,8 @@ where
 {
     fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
         let offset = self.0.len();
-        if let r @ Some(_) = self.0.get_mut(index) {
-            r
+        if index < offset {
+            self.0.get_mut(index)
         } else {
             self.1.get_mut(index - offset)
         }
@@ -68,3 +81,257 @@ where
     S2: Unique,
 {
 }
+
+// SAFETY: each side is only reached through a raw pointer to its field, and
+// its handle upholds the rest
+unsafe impl<S1, S2> SliceRawMut for Chain<S1, S2>
+where
+    S1: SliceRawMut,
+    S2: SliceRawMut<Output = S1::Output>,
+{
+    type Raw = Chain<S1::Raw, S2::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            Chain(
+                S1::raw_mut(&raw mut (*this).0),
+                S2::raw_mut(&raw mut (*this).1),
+            )
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and both sides
+// uphold the rest
+unsafe impl<R1, R2> RawSlice for Chain<R1, R2>
+where
+    R1: RawSlice,
+    R2: RawSlice<Output = R1::Output>,
+{
+    type Output = R1::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller
+        unsafe { self.0.len() + self.1.len() }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            let offset = self.0.len();
+            if index < offset {
+                self.0.get_mut(index)
+            } else {
+                self.1.get_mut(index - offset)
+            }
+        }
+    }
+}
+
+/// Any number of chained slices, with `O(log n)` indexing; see
+/// [`ChainMany::from`].
+///
+/// Unlike nested [`Chain`]s, each access binary searches a table of where each
+/// slice ends, which is computed once on creation. The slices are stored in
+/// `C`, which is an array or `Vec` of them, and the table in `P`, which is an
+/// array or `Vec` of the same length.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slice_utils::{ChainMany, SliceBorrowed};
+/// let pages = [[1, 2], [3, 4], [5, 6]];
+/// let chained = ChainMany::from(pages);
+///
+/// assert_eq!(chained, [1, 2, 3, 4, 5, 6]);
+/// assert_eq!(chained.get(3), Some(&4));
+/// ```
+#[derive(Clone, Copy, Hash)]
+pub struct ChainMany<C, P> {
+    slices: C,
+    ends: P,
+}
+
+impl<S, const N: usize> From<[S; N]> for ChainMany<[S; N], [usize; N]>
+where
+    S: Slice,
+{
+    fn from(slices: [S; N]) -> Self {
+        // saturating keeps the table sorted, so `locate` still works when a
+        // slice is infinite
+        let mut end = 0_usize;
+        let ends = core::array::from_fn(|i| {
+            end = end.saturating_add(slices[i].len());
+            end
+        });
+
+        Self { slices, ends }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> From<alloc::vec::Vec<S>> for ChainMany<alloc::vec::Vec<S>, alloc::vec::Vec<usize>>
+where
+    S: Slice,
+{
+    fn from(slices: alloc::vec::Vec<S>) -> Self {
+        let ends = slices
+            .iter()
+            .scan(0_usize, |end, s| {
+                *end = end.saturating_add(s.len());
+                Some(*end)
+            })
+            .collect();
+
+        Self { slices, ends }
+    }
+}
+
+impl<C, P> ChainMany<C, P>
+where
+    C: ContiguousBorrowed,
+    P: ContiguousBorrowed<Output = usize>,
+{
+    /// Finds which slice `index` falls in, and the index within that slice.
+    fn locate(&self, index: usize) -> Option<(usize, usize)> {
+        locate(self.ends.contiguous(), index)
+    }
+
+    /// Returns the chained slices.
+    pub fn into_inner(self) -> C {
+        self.slices
+    }
+}
+
+impl<C, P> Slice for ChainMany<C, P>
+where
+    C: ContiguousBorrowed,
+    P: ContiguousBorrowed<Output = usize>,
+    C::Output: Slice,
+{
+    type Output = <C::Output as Slice>::Output;
+
+    fn len(&self) -> usize {
+        self.ends.contiguous().last().copied().unwrap_or(0)
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        let (n, i) = self.locate(index)?;
+        self.slices.contiguous()[n].get_with(i, f)
+    }
+}
+
+impl<C, P> SliceOwned for ChainMany<C, P>
+where
+    C: ContiguousBorrowed,
+    P: ContiguousBorrowed<Output = usize>,
+    C::Output: SliceOwned,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        let (n, i) = self.locate(index)?;
+        self.slices.contiguous()[n].get_owned(i)
+    }
+}
+
+impl<C, P> SliceBorrowed for ChainMany<C, P>
+where
+    C: ContiguousBorrowed,
+    P: ContiguousBorrowed<Output = usize>,
+    C::Output: SliceBorrowed,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        let (n, i) = self.locate(index)?;
+        self.slices.contiguous()[n].get(i)
+    }
+}
+
+impl<C, P> SliceMut for ChainMany<C, P>
+where
+    C: ContiguousBorrowed + ContiguousMut,
+    P: ContiguousBorrowed<Output = usize>,
+    C::Output: SliceMut,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        let (n, i) = self.locate(index)?;
+        self.slices.contiguous_mut()[n].get_mut(i)
+    }
+}
+
+// SAFETY: the slices are stored contiguously, so are distinct, and each is
+// `Unique`; the table of ends is never changed, so every index maps to one
+// element of one slice
+unsafe impl<C, P> Unique for ChainMany<C, P>
+where
+    C: ContiguousBorrowed,
+    C::Output: Unique,
+{
+}
+
+// SAFETY: the slices are only reached through their handle, and the table of
+// ends is only read
+unsafe impl<C, P> SliceRawMut for ChainMany<C, P>
+where
+    C: ContiguousBorrowed + ContiguousMut + SliceRawMut,
+    P: ContiguousBorrowed<Output = usize>,
+    C::Output: SliceRawMut,
+{
+    type Raw = ChainMany<C::Raw, *const [usize]>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            ChainMany::raw(
+                C::raw_mut(&raw mut (*this).slices),
+                (*this).ends.contiguous(),
+            )
+        }
+    }
+}
+
+impl<R> ChainMany<R, *const [usize]>
+where
+    R: RawSlice,
+{
+    /// Creates a handle to chained slices, from a handle to the slices and
+    /// the table of where each ends.
+    pub(crate) fn raw(slices: R, ends: *const [usize]) -> Self {
+        Self { slices, ends }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, each slice is
+// reached through its own handle, and the table of ends outlives the handle
+unsafe impl<R> RawSlice for ChainMany<R, *const [usize]>
+where
+    R: RawSlice,
+    R::Output: SliceRawMut,
+{
+    type Output = <R::Output as Slice>::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller; the table is never written to
+        unsafe { (&*self.ends).last().copied().unwrap_or(0) }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            let (n, i) = locate(&*self.ends, index)?;
+            R::Output::raw_mut(self.slices.get_mut(n)?).get_mut(i)
+        }
+    }
+}
+
+/// Finds which slice `index` falls in, and the index within that slice, given
+/// where each slice ends.
+pub(crate) fn locate(ends: &[usize], index: usize) -> Option<(usize, usize)> {
+    let n = ends.partition_point(|&end| end <= index);
+    if n == ends.len() {
+        None
+    } else if n == 0 {
+        Some((0, index))
+    } else {
+        Some((n, index - ends[n - 1]))
+    }
+}
@@ -1,22 +1,24 @@
 use core::fmt;
 
 use crate::{
-    ArrayWindowsBorrowed, ArrayWindowsOwned, Chain, Cycle, FromFn, Interleave, MapBorrowed,
-    MapOwned, Reverse, Slice, SliceBorrowed, SliceOf, SliceOwned, SplitMut, WindowsBorrowed,
-    WindowsOwned, Zip,
+    ArrayWindowsBorrowed, ArrayWindowsOwned, Chain, ChainMany, ContiguousBorrowed, Cycle,
+    EnumerateBorrowed, EnumerateOwned, FlattenUniform, FromFn, Gather, Interleave,
+    InterleaveLongest, InterleaveN, InterleaveRatio, Lane, Lens, MapBorrowed, MapMut, MapOwned,
+    MapRef, MapWithIndexBorrowed, MapWithIndexOwned, Pair, Permute, Product, Reverse, Rotate,
+    Slice, SliceBorrowed, SliceMut, SliceOf, SliceOwned, SliceRawMut, SplitMut, StepBy, UnzipLeft,
+    UnzipLeftMut, UnzipRight, UnzipRightMut, WindowsBorrowed, WindowsOwned, Zip, ZipBorrowed,
+    ZipMut,
 };
+#[cfg(feature = "alloc")]
+use crate::{Flatten, Join};
 
 macro_rules! impl_debug {
     ($(
-        $typ:ident [$($lt:lifetime),* $($generics:ident),*]
+        impl[$($params:tt)*] $typ:ty where [$($bounds:tt)*]
     ;)*) => {$(
-        impl<
-            $($lt,)* T, S $(, $generics)*,
-        > fmt::Debug for $typ<$($lt,)* S $(, $generics)*>
+        impl<$($params)*> fmt::Debug for $typ
         where
-            T: fmt::Debug,
-            S: Slice<Output = T>,
-            $( $generics: Slice<Output = T>,)*
+            $($bounds)*
         {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 let mut list = f.debug_list();
@@ -27,14 +29,77 @@ macro_rules! impl_debug {
             }
         }
     )*};
+    ($(
+        $typ:ident [$($lt:lifetime),* $($generics:ident),*]
+    ;)*) => {
+        impl_debug! {$(
+            impl[$($lt,)* T, S $(, $generics)*] $typ<$($lt,)* S $(, $generics)*> where [
+                T: fmt::Debug,
+                S: Slice<Output = T>,
+                $( $generics: Slice<Output = T>,)*
+            ];
+        )*}
+    };
 }
 
 impl_debug! {
     Chain[S2];
     Interleave[S2];
+    InterleaveLongest[S2];
+    InterleaveRatio[S2];
     Reverse[];
+    Rotate[];
     SliceOf[];
-    SplitMut['a];
+    StepBy[];
+}
+
+impl_debug! {
+    impl[A] Lane<'_, A> where [A: SliceRawMut + ?Sized, A::Output: fmt::Debug];
+    impl[A] SplitMut<'_, A> where [A: SliceRawMut + ?Sized, A::Output: fmt::Debug];
+}
+
+impl_debug! {
+    impl[C, P] ChainMany<C, P> where [
+        C: ContiguousBorrowed,
+        P: ContiguousBorrowed<Output = usize>,
+        C::Output: Slice,
+        <C::Output as Slice>::Output: fmt::Debug,
+    ];
+}
+
+#[cfg(feature = "alloc")]
+impl_debug! {
+    impl[S] Flatten<S> where [
+        S: Slice,
+        S::Output: Slice,
+        <S::Output as Slice>::Output: fmt::Debug,
+    ];
+}
+
+impl_debug! {
+    impl[S] FlattenUniform<S> where [
+        S: Slice,
+        S::Output: Slice,
+        <S::Output as Slice>::Output: fmt::Debug,
+    ];
+}
+
+#[cfg(feature = "alloc")]
+impl_debug! {
+    impl[S, J] Join<S, J> where [
+        S: Slice,
+        S::Output: Slice,
+        J: Slice<Output = <S::Output as Slice>::Output>,
+        <S::Output as Slice>::Output: fmt::Debug,
+    ];
+}
+
+impl_debug! {
+    impl[C] InterleaveN<C> where [
+        C: ContiguousBorrowed,
+        C::Output: Slice,
+        <C::Output as Slice>::Output: fmt::Debug,
+    ];
 }
 
 // Separate impl to avoid infinite debug printing
@@ -96,6 +161,46 @@ where
     }
 }
 
+impl_debug! {
+    impl[S, F, U] MapRef<S, F> where [
+        S: Slice,
+        F: Fn(&S::Output) -> &U,
+        U: fmt::Debug,
+    ];
+}
+
+impl_debug! {
+    impl[S, F, G, U] MapMut<S, F, G> where [
+        S: Slice,
+        F: Fn(&S::Output) -> &U,
+        G: Fn(&mut S::Output) -> &mut U,
+        U: fmt::Debug,
+    ];
+}
+
+impl_debug! {
+    impl[S, G, P, U] Lens<S, G, P> where [
+        S: Slice,
+        G: Fn(&S::Output) -> U,
+        U: fmt::Debug,
+    ];
+}
+
+impl_debug! {
+    impl[T, S, F, U] MapWithIndexOwned<S, F> where [
+        S: SliceOwned<Output = T>,
+        F: Fn(usize, T) -> U,
+        U: fmt::Debug,
+    ];
+    impl[T, S, F, U] MapWithIndexBorrowed<S, F> where [
+        S: SliceBorrowed<Output = T>,
+        F: Fn(usize, &T) -> U,
+        U: fmt::Debug,
+    ];
+    impl[S] EnumerateOwned<S> where [S: SliceOwned, S::Output: fmt::Debug];
+    impl[S] EnumerateBorrowed<'_, S> where [S: SliceBorrowed + ?Sized, S::Output: fmt::Debug];
+}
+
 impl<F, T> fmt::Debug for FromFn<F>
 where
     F: Fn(usize) -> Option<T>,
@@ -112,11 +217,12 @@ where
     }
 }
 
-impl<T, S1, S2> fmt::Debug for Zip<S1, S2>
+impl<S1, S2> fmt::Debug for Zip<S1, S2>
 where
-    T: fmt::Debug,
-    S1: SliceOwned<Output = T>,
-    S2: SliceOwned<Output = T>,
+    S1: SliceOwned,
+    S2: SliceOwned,
+    S1::Output: fmt::Debug,
+    S2::Output: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut list = f.debug_list();
@@ -129,6 +235,72 @@ where
     }
 }
 
+impl_debug! {
+    impl[S1, S2] ZipBorrowed<'_, S1, S2> where [
+        S1: SliceBorrowed + ?Sized,
+        S2: SliceBorrowed + ?Sized,
+        S1::Output: fmt::Debug,
+        S2::Output: fmt::Debug,
+    ];
+}
+
+impl<S1, S2> fmt::Debug for ZipMut<S1, S2>
+where
+    S1: SliceBorrowed + SliceMut,
+    S2: SliceBorrowed + SliceMut,
+    S1::Output: fmt::Debug,
+    S2::Output: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl_debug! {
+    impl[S] UnzipLeft<S> where [
+        S: SliceBorrowed,
+        S::Output: Pair,
+        <S::Output as Pair>::Left: fmt::Debug,
+    ];
+    impl[S] UnzipLeftMut<'_, S> where [
+        S: SliceRawMut + ?Sized,
+        S::Output: Pair,
+        <S::Output as Pair>::Left: fmt::Debug,
+    ];
+    impl[S] UnzipRight<S> where [
+        S: SliceBorrowed,
+        S::Output: Pair,
+        <S::Output as Pair>::Right: fmt::Debug,
+    ];
+    impl[S] UnzipRightMut<'_, S> where [
+        S: SliceRawMut + ?Sized,
+        S::Output: Pair,
+        <S::Output as Pair>::Right: fmt::Debug,
+    ];
+}
+
+impl_debug! {
+    impl[T, S, I] Gather<S, I> where [
+        T: fmt::Debug,
+        S: Slice<Output = T>,
+        I: SliceOwned<Output = usize>,
+    ];
+    impl[T, S, I] Permute<S, I> where [
+        T: fmt::Debug,
+        S: Slice<Output = T>,
+        I: ContiguousBorrowed<Output = usize>,
+    ];
+}
+
+impl_debug! {
+    impl[S1, S2] Product<S1, S2> where [
+        S1: SliceOwned,
+        S2: SliceOwned,
+        S1::Output: fmt::Debug,
+        S2::Output: fmt::Debug,
+    ];
+}
+
 impl<'a, S, T> fmt::Debug for WindowsOwned<'a, S>
 where
     S: SliceOwned<Output = T>,
@@ -0,0 +1,59 @@
+use crate::{Slice, SliceBorrowed, SliceOwned};
+
+/// A slice returning each item along with its index; see
+/// [`SliceOwned::enumerate`].
+#[derive(Clone, Copy, Hash)]
+pub struct EnumerateOwned<S>(pub S);
+
+impl<S> Slice for EnumerateOwned<S>
+where
+    S: SliceOwned,
+{
+    type Output = (usize, S::Output);
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        Some(f(&self.get_owned(index)?))
+    }
+}
+
+impl<S> SliceOwned for EnumerateOwned<S>
+where
+    S: SliceOwned,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        Some((index, self.0.get_owned(index)?))
+    }
+}
+
+/// A slice returning a reference to each item along with its index; see
+/// [`SliceBorrowed::enumerate`].
+#[derive(Clone, Copy)]
+pub struct EnumerateBorrowed<'a, S: ?Sized>(pub &'a S);
+
+impl<'a, S> Slice for EnumerateBorrowed<'a, S>
+where
+    S: SliceBorrowed + ?Sized,
+{
+    type Output = (usize, &'a S::Output);
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        Some(f(&self.get_owned(index)?))
+    }
+}
+
+impl<S> SliceOwned for EnumerateBorrowed<'_, S>
+where
+    S: SliceBorrowed + ?Sized,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        Some((index, self.0.get(index)?))
+    }
+}
@@ -1,21 +1,21 @@
 use crate::{
-    ArrayWindowsBorrowed, ArrayWindowsOwned, Chain, Cycle, FromFn, Interleave, MapBorrowed,
-    MapOwned, Reverse, Slice, SliceBorrowed, SliceOf, SliceOwned, SplitMut, WindowsBorrowed,
-    WindowsOwned, Zip,
+    ArrayWindowsBorrowed, ArrayWindowsOwned, Chain, ChainMany, ContiguousBorrowed, Cycle,
+    EnumerateBorrowed, EnumerateOwned, FlattenUniform, FromFn, Gather, Interleave,
+    InterleaveLongest, InterleaveN, InterleaveRatio, Lane, Lens, MapBorrowed, MapMut, MapOwned,
+    MapRef, MapWithIndexBorrowed, MapWithIndexOwned, Pair, Permute, Product, Reverse, Rotate,
+    Slice, SliceBorrowed, SliceOf, SliceOwned, SliceRawMut, SplitMut, StepBy, UnzipLeft,
+    UnzipLeftMut, UnzipRight, UnzipRightMut, WindowsBorrowed, WindowsOwned, Zip, ZipBorrowed,
 };
+#[cfg(feature = "alloc")]
+use crate::{Flatten, Join};
 
 macro_rules! impl_eq {
     ($(
-        $typ:ident [$($lt:lifetime),* $($generics:ident),*]
+        impl[$($params:tt)*] $typ:ty where [$($bounds:tt)*]
     ;)*) => {$(
-        impl<
-            $($lt,)* T, S, O, V $(, $generics)*,
-        > PartialEq<O> for $typ<$($lt,)* S $(, $generics)*>
+        impl<$($params)*> PartialEq<O> for $typ
         where
-            V: PartialEq<T>,
-            S: SliceOwned<Output = T>,
-            O: Slice<Output = V>,
-            $( $generics: SliceOwned<Output = T>,)*
+            $($bounds)*
         {
             fn eq(&self, other: &O) -> bool {
                 if self.len() != other.len() {
@@ -23,7 +23,9 @@ macro_rules! impl_eq {
                 } else {
                     for i in 0..self.len() {
                         if other
-                            .get_with(i, &mut |x| x != &self.get_owned(i).unwrap())
+                            .get_with(i, &mut |x| {
+                                self.get_with(i, &mut |y| x != y).unwrap_or(true)
+                            })
                             .unwrap_or(true)
                         {
                             return false;
@@ -35,15 +37,92 @@ macro_rules! impl_eq {
             }
         }
     )*};
+    ($(
+        $typ:ident [$($lt:lifetime),* $($generics:ident),*]
+    ;)*) => {
+        impl_eq! {$(
+            impl[$($lt,)* T, S, O, V $(, $generics)*] $typ<$($lt,)* S $(, $generics)*> where [
+                V: PartialEq<T>,
+                S: SliceOwned<Output = T>,
+                O: Slice<Output = V>,
+                $( $generics: SliceOwned<Output = T>,)*
+            ];
+        )*}
+    };
 }
 
 impl_eq! {
     Chain[S2];
     Cycle[];
     Interleave[S2];
+    InterleaveLongest[S2];
+    InterleaveRatio[S2];
     Reverse[];
+    Rotate[];
     SliceOf[];
-    SplitMut['a];
+    StepBy[];
+}
+
+impl_eq! {
+    impl[A, O, V] Lane<'_, A> where [
+        A: SliceRawMut + ?Sized,
+        O: Slice<Output = V>,
+        V: PartialEq<A::Output>,
+    ];
+    impl[A, O, V] SplitMut<'_, A> where [
+        A: SliceRawMut + ?Sized,
+        O: Slice<Output = V>,
+        V: PartialEq<A::Output>,
+    ];
+}
+
+impl_eq! {
+    impl[C, P, O, V] ChainMany<C, P> where [
+        C: ContiguousBorrowed,
+        P: ContiguousBorrowed<Output = usize>,
+        C::Output: Slice,
+        O: Slice<Output = V>,
+        V: PartialEq<<C::Output as Slice>::Output>,
+    ];
+}
+
+#[cfg(feature = "alloc")]
+impl_eq! {
+    impl[S, O, V] Flatten<S> where [
+        S: Slice,
+        S::Output: Slice,
+        O: Slice<Output = V>,
+        V: PartialEq<<S::Output as Slice>::Output>,
+    ];
+}
+
+impl_eq! {
+    impl[S, O, V] FlattenUniform<S> where [
+        S: Slice,
+        S::Output: Slice,
+        O: Slice<Output = V>,
+        V: PartialEq<<S::Output as Slice>::Output>,
+    ];
+}
+
+#[cfg(feature = "alloc")]
+impl_eq! {
+    impl[S, J, O, V] Join<S, J> where [
+        S: Slice,
+        S::Output: Slice,
+        J: Slice<Output = <S::Output as Slice>::Output>,
+        O: Slice<Output = V>,
+        V: PartialEq<<S::Output as Slice>::Output>,
+    ];
+}
+
+impl_eq! {
+    impl[C, O, V] InterleaveN<C> where [
+        C: ContiguousBorrowed,
+        C::Output: Slice,
+        O: Slice<Output = V>,
+        V: PartialEq<<C::Output as Slice>::Output>,
+    ];
 }
 
 impl<T, S, O, F, U, V> PartialEq<O> for MapOwned<S, F>
@@ -96,6 +175,59 @@ where
     }
 }
 
+impl_eq! {
+    impl[S, O, F, U, V] MapRef<S, F> where [
+        S: Slice,
+        O: Slice<Output = V>,
+        F: Fn(&S::Output) -> &U,
+        V: PartialEq<U>,
+    ];
+}
+
+impl_eq! {
+    impl[S, O, F, G, U, V] MapMut<S, F, G> where [
+        S: Slice,
+        O: Slice<Output = V>,
+        F: Fn(&S::Output) -> &U,
+        G: Fn(&mut S::Output) -> &mut U,
+        V: PartialEq<U>,
+    ];
+}
+
+impl_eq! {
+    impl[S, O, G, P, U, V] Lens<S, G, P> where [
+        S: Slice,
+        O: Slice<Output = V>,
+        G: Fn(&S::Output) -> U,
+        V: PartialEq<U>,
+    ];
+}
+
+impl_eq! {
+    impl[T, S, O, F, U, V] MapWithIndexOwned<S, F> where [
+        S: SliceOwned<Output = T>,
+        O: Slice<Output = V>,
+        F: Fn(usize, T) -> U,
+        V: PartialEq<U>,
+    ];
+    impl[T, S, O, F, U, V] MapWithIndexBorrowed<S, F> where [
+        S: SliceBorrowed<Output = T>,
+        O: Slice<Output = V>,
+        F: Fn(usize, &T) -> U,
+        V: PartialEq<U>,
+    ];
+    impl[T, S, O, U] EnumerateOwned<S> where [
+        S: SliceOwned<Output = T>,
+        O: Slice<Output = U>,
+        U: PartialEq<(usize, T)>,
+    ];
+    impl['a, T: 'a, S, O, U] EnumerateBorrowed<'a, S> where [
+        S: SliceBorrowed<Output = T> + ?Sized,
+        O: Slice<Output = U>,
+        U: PartialEq<(usize, &'a T)>,
+    ];
+}
+
 impl<T, O, F, U> PartialEq<O> for FromFn<F>
 where
     O: Slice<Output = U>,
@@ -120,12 +252,12 @@ where
     }
 }
 
-impl<T, O, U, S1, S2> PartialEq<O> for Zip<S1, S2>
+impl<O, U, S1, S2> PartialEq<O> for Zip<S1, S2>
 where
     O: Slice<Output = U>,
-    U: PartialEq<(T, T)>,
-    S1: SliceOwned<Output = T>,
-    S2: SliceOwned<Output = T>,
+    U: PartialEq<(S1::Output, S2::Output)>,
+    S1: SliceOwned,
+    S2: SliceOwned,
 {
     fn eq(&self, other: &O) -> bool {
         if self.len() != other.len() {
@@ -145,6 +277,66 @@ where
     }
 }
 
+impl_eq! {
+    impl['a, A: 'a, B: 'a, O, U, S1, S2] ZipBorrowed<'a, S1, S2> where [
+        O: Slice<Output = U>,
+        U: PartialEq<(&'a A, &'a B)>,
+        S1: SliceBorrowed<Output = A> + ?Sized,
+        S2: SliceBorrowed<Output = B> + ?Sized,
+    ];
+}
+
+impl_eq! {
+    impl[S, O, V] UnzipLeft<S> where [
+        S: SliceBorrowed,
+        S::Output: Pair,
+        O: Slice<Output = V>,
+        V: PartialEq<<S::Output as Pair>::Left>,
+    ];
+    impl[S, O, V] UnzipLeftMut<'_, S> where [
+        S: SliceRawMut + ?Sized,
+        S::Output: Pair,
+        O: Slice<Output = V>,
+        V: PartialEq<<S::Output as Pair>::Left>,
+    ];
+    impl[S, O, V] UnzipRight<S> where [
+        S: SliceBorrowed,
+        S::Output: Pair,
+        O: Slice<Output = V>,
+        V: PartialEq<<S::Output as Pair>::Right>,
+    ];
+    impl[S, O, V] UnzipRightMut<'_, S> where [
+        S: SliceRawMut + ?Sized,
+        S::Output: Pair,
+        O: Slice<Output = V>,
+        V: PartialEq<<S::Output as Pair>::Right>,
+    ];
+}
+
+impl_eq! {
+    impl[T, S, I, O, V] Gather<S, I> where [
+        V: PartialEq<T>,
+        S: SliceOwned<Output = T>,
+        I: SliceOwned<Output = usize>,
+        O: Slice<Output = V>,
+    ];
+    impl[T, S, I, O, V] Permute<S, I> where [
+        V: PartialEq<T>,
+        S: SliceOwned<Output = T>,
+        I: ContiguousBorrowed<Output = usize>,
+        O: Slice<Output = V>,
+    ];
+}
+
+impl_eq! {
+    impl[O, U, S1, S2] Product<S1, S2> where [
+        O: Slice<Output = U>,
+        U: PartialEq<(S1::Output, S2::Output)>,
+        S1: SliceOwned,
+        S2: SliceOwned,
+    ];
+}
+
 impl<'a, T, U, O, S> PartialEq<O> for WindowsOwned<'a, S>
 where
     O: SliceOwned<Output = U>,
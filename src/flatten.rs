@@ -0,0 +1,283 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::ChainMany;
+use crate::{RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned, SliceRawMut, Unique};
+
+/// A slice of slices flattened into one, where every inner slice has the same
+/// length; see [`Slice::flatten_uniform`].
+#[derive(Clone, Copy, Hash)]
+pub struct FlattenUniform<S> {
+    data: S,
+    width: usize,
+}
+
+impl<S> FlattenUniform<S>
+where
+    S: Slice,
+    S::Output: Slice,
+{
+    /// Creates a flattened slice; see [`Slice::flatten_uniform`].
+    pub fn new(data: S, width: usize) -> Self {
+        Self { data, width }
+    }
+
+    fn translate(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len() {
+            None
+        } else {
+            Some((index / self.width, index % self.width))
+        }
+    }
+}
+
+/// Panics if the inner slice at `outer` is shorter than `width`, rather than
+/// leaving gaps in the flattened slice.
+#[track_caller]
+fn check_width(outer: usize, len: usize, width: usize) {
+    if len < width {
+        panic!("inner slice {outer} is too short: the len is {len} but the width is {width}");
+    }
+}
+
+impl<S> Slice for FlattenUniform<S>
+where
+    S: Slice,
+    S::Output: Slice,
+{
+    type Output = <S::Output as Slice>::Output;
+
+    fn len(&self) -> usize {
+        self.data.len().saturating_mul(self.width)
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        let (outer, inner) = self.translate(index)?;
+        self.data
+            .get_with(outer, &mut |x| {
+                check_width(outer, x.len(), self.width);
+                x.get_with(inner, f)
+            })
+            .flatten()
+    }
+}
+
+impl<S> SliceOwned for FlattenUniform<S>
+where
+    S: Slice,
+    S::Output: SliceOwned,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        let (outer, inner) = self.translate(index)?;
+        self.data
+            .get_with(outer, &mut |x| {
+                check_width(outer, x.len(), self.width);
+                x.get_owned(inner)
+            })
+            .flatten()
+    }
+}
+
+impl<S> SliceBorrowed for FlattenUniform<S>
+where
+    S: SliceBorrowed,
+    S::Output: SliceBorrowed,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        let (outer, inner) = self.translate(index)?;
+        let x = self.data.get(outer)?;
+        check_width(outer, x.len(), self.width);
+        x.get(inner)
+    }
+}
+
+impl<S> SliceMut for FlattenUniform<S>
+where
+    S: SliceMut,
+    S::Output: SliceMut,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        let (outer, inner) = self.translate(index)?;
+        let x = self.data.get_mut(outer)?;
+        check_width(outer, x.len(), self.width);
+        x.get_mut(inner)
+    }
+}
+
+// SAFETY: the outer and inner slices are `Unique`, and each index maps to a
+// distinct pair of outer and inner indices
+unsafe impl<S> Unique for FlattenUniform<S>
+where
+    S: Slice + Unique,
+    S::Output: Unique,
+{
+}
+
+// SAFETY: the outer slice is only reached through its handle, and the inner
+// slices through theirs
+unsafe impl<S> SliceRawMut for FlattenUniform<S>
+where
+    S: SliceRawMut,
+    S::Output: SliceRawMut,
+{
+    type Raw = FlattenUniform<S::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            FlattenUniform {
+                data: S::raw_mut(&raw mut (*this).data),
+                width: (*this).width,
+            }
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and each inner slice
+// is reached through its own handle
+unsafe impl<R> RawSlice for FlattenUniform<R>
+where
+    R: RawSlice,
+    R::Output: SliceRawMut,
+{
+    type Output = <R::Output as Slice>::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller
+        unsafe { self.data.len().saturating_mul(self.width) }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            if index >= self.len() {
+                None
+            } else {
+                let outer = index / self.width;
+                let inner = R::Output::raw_mut(self.data.get_mut(outer)?);
+                check_width(outer, inner.len(), self.width);
+                inner.get_mut(index % self.width)
+            }
+        }
+    }
+}
+
+/// A slice of slices flattened into one; see [`Slice::flatten`]. Only
+/// available on feature `alloc`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Hash)]
+pub struct Flatten<S> {
+    data: S,
+    ends: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<S> Flatten<S>
+where
+    S: Slice,
+    S::Output: Slice,
+{
+    /// Creates a flattened slice, measuring each inner slice up-front; see
+    /// [`Slice::flatten`].
+    pub fn new(data: S) -> Self {
+        let mut ends = Vec::with_capacity(data.len());
+        let mut end = 0_usize;
+        for i in 0..data.len() {
+            let len = data.get_with(i, &mut |x| x.len()).unwrap_or(0);
+            end = end.saturating_add(len);
+            ends.push(end);
+        }
+
+        Self { data, ends }
+    }
+
+    fn translate(&self, index: usize) -> Option<(usize, usize)> {
+        crate::chain::locate(&self.ends, index)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> Slice for Flatten<S>
+where
+    S: Slice,
+    S::Output: Slice,
+{
+    type Output = <S::Output as Slice>::Output;
+
+    fn len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        let (outer, inner) = self.translate(index)?;
+        self.data
+            .get_with(outer, &mut |x| x.get_with(inner, f))
+            .flatten()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> SliceOwned for Flatten<S>
+where
+    S: Slice,
+    S::Output: SliceOwned,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        let (outer, inner) = self.translate(index)?;
+        self.data
+            .get_with(outer, &mut |x| x.get_owned(inner))
+            .flatten()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> SliceBorrowed for Flatten<S>
+where
+    S: SliceBorrowed,
+    S::Output: SliceBorrowed,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        let (outer, inner) = self.translate(index)?;
+        self.data.get(outer)?.get(inner)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S> SliceMut for Flatten<S>
+where
+    S: SliceMut,
+    S::Output: SliceMut,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        let (outer, inner) = self.translate(index)?;
+        self.data.get_mut(outer)?.get_mut(inner)
+    }
+}
+
+// SAFETY: the outer and inner slices are `Unique`, and the table of ends is
+// never changed, so each index maps to a distinct pair of outer and inner
+// indices
+#[cfg(feature = "alloc")]
+unsafe impl<S> Unique for Flatten<S>
+where
+    S: Slice + Unique,
+    S::Output: Unique,
+{
+}
+
+// SAFETY: the outer slice is only reached through its handle, the inner slices
+// through theirs, and the table of ends is only read
+#[cfg(feature = "alloc")]
+unsafe impl<S> SliceRawMut for Flatten<S>
+where
+    S: SliceRawMut,
+    S::Output: SliceRawMut,
+{
+    type Raw = ChainMany<S::Raw, *const [usize]>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe { ChainMany::raw(S::raw_mut(&raw mut (*this).data), &*(*this).ends) }
+    }
+}
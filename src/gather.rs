@@ -0,0 +1,214 @@
+use crate::{
+    ContiguousBorrowed, RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned, SliceRawMut,
+    StableContiguous, Unique,
+};
+
+/// A slice indexed through a slice of indices; see [`Slice::gather`].
+#[derive(Clone, Copy, Hash)]
+pub struct Gather<S, I>(pub S, pub I);
+
+impl<S, I> Slice for Gather<S, I>
+where
+    S: Slice,
+    I: SliceOwned<Output = usize>,
+{
+    type Output = S::Output;
+
+    fn len(&self) -> usize {
+        self.1.len()
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        self.0.get_with(self.1.get_owned(index)?, f)
+    }
+}
+
+impl<S, I> SliceOwned for Gather<S, I>
+where
+    S: SliceOwned,
+    I: SliceOwned<Output = usize>,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        self.0.get_owned(self.1.get_owned(index)?)
+    }
+}
+
+impl<S, I> SliceBorrowed for Gather<S, I>
+where
+    S: SliceBorrowed,
+    I: SliceOwned<Output = usize>,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        self.0.get(self.1.get_owned(index)?)
+    }
+}
+
+impl<S, I> SliceMut for Gather<S, I>
+where
+    S: SliceMut,
+    I: SliceOwned<Output = usize>,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        self.0.get_mut(self.1.get_owned(index)?)
+    }
+}
+
+/// A slice reordered by a permutation of its indices; see [`Slice::permute`].
+///
+/// Unlike [`Gather`], every element is visited exactly once, so this is
+/// [`Unique`] whenever the underlying slice is, and the indices are
+/// [`StableContiguous`].
+#[derive(Clone, Copy, Hash)]
+pub struct Permute<S, I> {
+    data: S,
+    indices: I,
+}
+
+impl<S, I> Permute<S, I>
+where
+    S: Slice,
+    I: ContiguousBorrowed<Output = usize>,
+{
+    /// Creates a permuted slice, returning `None` if `indices` isn't a
+    /// permutation of `0..data.len()`; see [`Slice::permute`].
+    pub fn new(data: S, indices: I) -> Option<Self> {
+        let len = data.len();
+        let idx = indices.contiguous();
+        if idx.len() != len {
+            return None;
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut seen = alloc::vec![false; len];
+            for &i in idx {
+                if i >= len || core::mem::replace(&mut seen[i], true) {
+                    return None;
+                }
+            }
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        for (n, &i) in idx.iter().enumerate() {
+            if i >= len || idx[..n].contains(&i) {
+                return None;
+            }
+        }
+
+        Some(Self { data, indices })
+    }
+
+    /// Creates a permuted slice without checking the indices.
+    ///
+    /// # Safety
+    ///
+    /// `indices` must be a permutation of `0..data.len()`.
+    #[cfg(feature = "alloc")]
+    pub(crate) unsafe fn new_unchecked(data: S, indices: I) -> Self {
+        Self { data, indices }
+    }
+
+    fn translate(&self, index: usize) -> Option<usize> {
+        self.indices.contiguous().get(index).copied()
+    }
+}
+
+impl<S, I> Slice for Permute<S, I>
+where
+    S: Slice,
+    I: ContiguousBorrowed<Output = usize>,
+{
+    type Output = S::Output;
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        self.data.get_with(self.translate(index)?, f)
+    }
+}
+
+impl<S, I> SliceOwned for Permute<S, I>
+where
+    S: SliceOwned,
+    I: ContiguousBorrowed<Output = usize>,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        self.data.get_owned(self.translate(index)?)
+    }
+}
+
+impl<S, I> SliceBorrowed for Permute<S, I>
+where
+    S: SliceBorrowed,
+    I: ContiguousBorrowed<Output = usize>,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        self.data.get(self.translate(index)?)
+    }
+}
+
+impl<S, I> SliceMut for Permute<S, I>
+where
+    S: SliceMut,
+    I: ContiguousBorrowed<Output = usize>,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        let i = self.translate(index)?;
+        self.data.get_mut(i)
+    }
+}
+
+// SAFETY: the underlying slice is `Unique`, and the indices were checked to be
+// a permutation on creation; they can't be changed afterwards, since they're
+// stored in memory we own or immutably borrow, and `StableContiguous`
+// guarantees they're read back the same every time
+unsafe impl<S, I> Unique for Permute<S, I>
+where
+    S: Unique,
+    I: StableContiguous,
+{
+}
+
+// SAFETY: only the indices are borrowed, and only immutably; the underlying
+// slice is only reached through a raw pointer to it
+unsafe impl<S, I> SliceRawMut for Permute<S, I>
+where
+    S: SliceRawMut,
+    I: StableContiguous<Output = usize>,
+{
+    type Raw = Permute<S::Raw, *const [usize]>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            Permute {
+                data: S::raw_mut(&raw mut (*this).data),
+                indices: (*this).indices.contiguous(),
+            }
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, the indices outlive
+// the handle, and the underlying handle upholds the rest
+unsafe impl<R> RawSlice for Permute<R, *const [usize]>
+where
+    R: RawSlice,
+{
+    type Output = R::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller
+        unsafe { self.data.len() }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller; the indices are never written to
+        unsafe {
+            let i = *(&*self.indices).get(index)?;
+            self.data.get_mut(i)
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use core::ops::{Range, RangeFrom, RangeInclusive};
+use core::ptr;
 
 use crate::{
-    ContiguousBorrowed, ContiguousMut, Slice, SliceBorrowed, SliceMut, SliceOwned, Unique,
+    ContiguousBorrowed, ContiguousMut, RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned,
+    SliceRawMut, StableContiguous, Unique,
 };
 
 impl<T, const N: usize> Slice for [T; N] {
@@ -35,6 +37,10 @@ impl<T, const N: usize> SliceMut for [T; N] {
     fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
         (index < N).then(|| &mut self[index])
     }
+
+    fn as_contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
 }
 
 impl<T, const N: usize> ContiguousBorrowed for [T; N] {
@@ -52,6 +58,18 @@ impl<T, const N: usize> ContiguousMut for [T; N] {
 // SAFETY: arrays are contiguous in memory
 unsafe impl<T, const N: usize> Unique for [T; N] {}
 
+// SAFETY: arrays can't change without mutable access
+unsafe impl<T, const N: usize> StableContiguous for [T; N] {}
+
+// SAFETY: only the pointer is cast, and arrays are contiguous
+unsafe impl<T, const N: usize> SliceRawMut for [T; N] {
+    type Raw = *mut [T];
+
+    unsafe fn raw_mut(this: *mut Self) -> *mut [T] {
+        ptr::slice_from_raw_parts_mut(this.cast::<T>(), N)
+    }
+}
+
 impl<T> Slice for [T] {
     type Output = T;
 
@@ -83,6 +101,10 @@ impl<T> SliceMut for [T] {
     fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
         (index < self.len()).then(|| &mut self[index])
     }
+
+    fn as_contiguous_mut(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
 }
 
 impl<T> ContiguousBorrowed for [T] {
@@ -100,7 +122,34 @@ impl<T> ContiguousMut for [T] {
 // SAFETY: slices are contiguous in memory
 unsafe impl<T> Unique for [T] {}
 
-impl<'a, S> Slice for &'a S
+// SAFETY: slices can't change without mutable access
+unsafe impl<T> StableContiguous for [T] {}
+
+// SAFETY: the pointer is the handle
+unsafe impl<T> SliceRawMut for [T] {
+    type Raw = *mut [T];
+
+    unsafe fn raw_mut(this: *mut Self) -> *mut [T] {
+        this
+    }
+}
+
+// SAFETY: the length is read from the pointer's metadata, and only pointer
+// arithmetic is done
+unsafe impl<T> RawSlice for *mut [T] {
+    type Output = T;
+
+    unsafe fn len(&self) -> usize {
+        <*mut [T]>::len(*self)
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut T> {
+        // SAFETY: the index is in-bounds
+        (index < <*mut [T]>::len(*self)).then(|| unsafe { self.cast::<T>().add(index) })
+    }
+}
+
+impl<S> Slice for &S
 where
     S: Slice + ?Sized,
 {
@@ -115,7 +164,7 @@ where
     }
 }
 
-impl<'a, S> SliceOwned for &'a S
+impl<S> SliceOwned for &S
 where
     S: SliceOwned + ?Sized,
 {
@@ -124,7 +173,7 @@ where
     }
 }
 
-impl<'a, S> SliceBorrowed for &'a S
+impl<S> SliceBorrowed for &S
 where
     S: SliceBorrowed + ?Sized,
 {
@@ -143,9 +192,12 @@ where
 }
 
 // SAFETY: the underlying slice is `Unique`
-unsafe impl<'a, S> Unique for &'a S where S: Unique + ?Sized {}
+unsafe impl<S> Unique for &S where S: Unique + ?Sized {}
+
+// SAFETY: the underlying slice is `StableContiguous`
+unsafe impl<S> StableContiguous for &S where S: StableContiguous + ?Sized {}
 
-impl<'a, S> Slice for &'a mut S
+impl<S> Slice for &mut S
 where
     S: Slice + ?Sized,
 {
@@ -160,7 +212,7 @@ where
     }
 }
 
-impl<'a, S> SliceOwned for &'a mut S
+impl<S> SliceOwned for &mut S
 where
     S: SliceOwned + ?Sized,
 {
@@ -169,7 +221,7 @@ where
     }
 }
 
-impl<'a, S> SliceBorrowed for &'a mut S
+impl<S> SliceBorrowed for &mut S
 where
     S: SliceBorrowed + ?Sized,
 {
@@ -178,13 +230,17 @@ where
     }
 }
 
-impl<'a, S> SliceMut for &'a mut S
+impl<S> SliceMut for &mut S
 where
     S: SliceMut + ?Sized,
 {
     fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
         (**self).get_mut(index)
     }
+
+    fn as_contiguous_mut(&mut self) -> Option<&mut [Self::Output]> {
+        (**self).as_contiguous_mut()
+    }
 }
 
 impl<S> ContiguousBorrowed for &'_ mut S
@@ -206,13 +262,37 @@ where
 }
 
 // SAFETY: the underlying slice is `Unique`
-unsafe impl<'a, S> Unique for &'a mut S where S: Unique + ?Sized {}
+unsafe impl<S> Unique for &mut S where S: Unique + ?Sized {}
+
+// SAFETY: the underlying slice is `StableContiguous`
+unsafe impl<S> StableContiguous for &mut S where S: StableContiguous + ?Sized {}
+
+// SAFETY: the reference is read as a raw pointer, so the underlying slice is
+// never reborrowed, and it upholds the rest
+unsafe impl<S> SliceRawMut for &mut S
+where
+    S: SliceRawMut + ?Sized,
+{
+    type Raw = S::Raw;
+
+    unsafe fn raw_mut(this: *mut Self) -> S::Raw {
+        // SAFETY: guaranteed by the caller, and `&mut S` has the same layout
+        // as `*mut S`
+        unsafe { S::raw_mut(this.cast::<*mut S>().read()) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod with_alloc {
+    use alloc::vec::Vec;
+    use core::mem::ManuallyDrop;
+    use core::ptr;
 
-#[cfg(feature = "std")]
-mod with_std {
     use crate::{
-        ContiguousBorrowed, ContiguousMut, Slice, SliceBorrowed, SliceMut, SliceOwned, Unique,
+        ContiguousBorrowed, ContiguousMut, Slice, SliceBorrowed, SliceMut, SliceOwned, SliceRawMut,
+        StableContiguous, Unique,
     };
+
     impl<T> Slice for Vec<T> {
         type Output = T;
 
@@ -244,11 +324,15 @@ mod with_std {
         fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
             (index < self.len()).then(|| &mut self[index])
         }
+
+        fn as_contiguous_mut(&mut self) -> Option<&mut [T]> {
+            Some(self)
+        }
     }
 
     impl<T> ContiguousBorrowed for Vec<T> {
         fn contiguous(&self) -> &[T] {
-            &*self
+            self
         }
     }
 
@@ -260,6 +344,24 @@ mod with_std {
 
     // SAFETY: vecs are contiguous in memory
     unsafe impl<T> Unique for Vec<T> {}
+
+    // SAFETY: vecs can't change without mutable access
+    unsafe impl<T> StableContiguous for Vec<T> {}
+
+    // SAFETY: the vec itself is only read, never borrowed, and `as_mut_ptr`
+    // doesn't create a reference to the items
+    unsafe impl<T> SliceRawMut for Vec<T> {
+        type Raw = *mut [T];
+
+        unsafe fn raw_mut(this: *mut Self) -> *mut [T] {
+            // Getting a mutable pointer to the buffer needs a `&mut Vec`,
+            // which counts as a write to the vec; borrow a copy that's never
+            // dropped instead, so that this can run on several threads at once
+            // SAFETY: guaranteed by the caller
+            let mut copy = ManuallyDrop::new(unsafe { ptr::read(this) });
+            ptr::slice_from_raw_parts_mut(copy.as_mut_ptr(), copy.len())
+        }
+    }
 }
 
 macro_rules! impl_for_range {
@@ -1,19 +1,19 @@
 use core::ops::{Index, IndexMut};
 
 use crate::{
-    Chain, Cycle, Interleave, Reverse, Slice, SliceBorrowed, SliceMut, SliceOf, SplitMut, Unique,
+    Chain, ChainMany, ContiguousBorrowed, ContiguousMut, Cycle, FlattenUniform, Gather, Interleave,
+    InterleaveLongest, InterleaveN, InterleaveRatio, Lane, MapMut, MapRef, Pair, Permute, Reverse,
+    Rotate, Slice, SliceBorrowed, SliceMut, SliceOf, SliceOwned, SliceRawMut, SplitMut, StepBy,
+    UnzipLeft, UnzipLeftMut, UnzipRight, UnzipRightMut,
 };
+#[cfg(feature = "alloc")]
+use crate::{Flatten, Join};
 
 macro_rules! impl_index {
-    ($(
-        $typ:ident [$($lt:lifetime),* $($generics:ident),*] $(= $unique:ident)?
-    ;)*) => {$(
-        impl<
-            $($lt,)* T, S $(, $generics)*,
-        > Index<usize> for $typ<$($lt,)* S $(, $generics)*>
+    (@index [$($params:tt)*] $typ:ty, [$($bounds:tt)*]) => {
+        impl<$($params)*> Index<usize> for $typ
         where
-            S: SliceBorrowed<Output = T>,
-            $( $generics: SliceBorrowed<Output = T>,)*
+            $($bounds)*
         {
             type Output = <Self as Slice>::Output;
 
@@ -23,13 +23,11 @@ macro_rules! impl_index {
                 })
             }
         }
-
-        impl<
-            $($lt,)* T, S $(, $generics)*,
-        > IndexMut<usize> for $typ<$($lt,)* S $(, $generics)*>
+    };
+    (@index_mut [$($params:tt)*] $typ:ty, [$($bounds:tt)*]) => {
+        impl<$($params)*> IndexMut<usize> for $typ
         where
-            S: SliceBorrowed<Output = T> + SliceMut $(+ $unique)?,
-            $( $generics: SliceBorrowed<Output = T> + SliceMut,)*
+            $($bounds)*
         {
             fn index_mut(&mut self, index: usize) -> &mut Self::Output {
                 let len = self.len();
@@ -38,14 +36,116 @@ macro_rules! impl_index {
                 })
             }
         }
+    };
+    ($(
+        impl $params:tt $typ:ty where $bounds:tt $(mut $mut_bounds:tt)?
+    ;)*) => {$(
+        impl_index!(@index $params $typ, $bounds);
+        $(impl_index!(@index_mut $params $typ, $mut_bounds);)?
     )*};
+    ($(
+        $typ:ident [$($lt:lifetime),* $($generics:ident),*]
+    ;)*) => {
+        impl_index! {$(
+            impl[$($lt,)* T, S $(, $generics)*] $typ<$($lt,)* S $(, $generics)*> where [
+                S: SliceBorrowed<Output = T>,
+                $( $generics: SliceBorrowed<Output = T>,)*
+            ] mut [
+                S: SliceBorrowed<Output = T> + SliceMut,
+                $( $generics: SliceBorrowed<Output = T> + SliceMut,)*
+            ];
+        )*}
+    };
 }
 
 impl_index! {
     Chain[S2];
     Cycle[];
     Interleave[S2];
+    InterleaveLongest[S2];
+    InterleaveRatio[S2];
     Reverse[];
+    Rotate[];
     SliceOf[];
-    SplitMut['a] = Unique;
+    StepBy[];
+}
+
+impl_index! {
+    impl[S] UnzipLeft<S> where [S: SliceBorrowed, S::Output: Pair]
+        mut [S: SliceBorrowed + SliceMut, S::Output: Pair];
+    impl[S] UnzipRight<S> where [S: SliceBorrowed, S::Output: Pair]
+        mut [S: SliceBorrowed + SliceMut, S::Output: Pair];
+    impl[S] UnzipLeftMut<'_, S> where [S: SliceRawMut + ?Sized, S::Output: Pair]
+        mut [S: SliceRawMut + ?Sized, S::Output: Pair];
+    impl[S] UnzipRightMut<'_, S> where [S: SliceRawMut + ?Sized, S::Output: Pair]
+        mut [S: SliceRawMut + ?Sized, S::Output: Pair];
+}
+
+impl_index! {
+    impl[A] Lane<'_, A> where [A: SliceRawMut + ?Sized] mut [A: SliceRawMut + ?Sized];
+    impl[A] SplitMut<'_, A> where [A: SliceRawMut + ?Sized] mut [A: SliceRawMut + ?Sized];
+}
+
+impl_index! {
+    impl[C, P] ChainMany<C, P> where [
+        C: ContiguousBorrowed,
+        P: ContiguousBorrowed<Output = usize>,
+        C::Output: SliceBorrowed,
+    ] mut [
+        C: ContiguousBorrowed + ContiguousMut,
+        P: ContiguousBorrowed<Output = usize>,
+        C::Output: SliceBorrowed + SliceMut,
+    ];
+}
+
+#[cfg(feature = "alloc")]
+impl_index! {
+    impl[S] Flatten<S> where [S: SliceBorrowed, S::Output: SliceBorrowed]
+        mut [S: SliceBorrowed + SliceMut, S::Output: SliceBorrowed + SliceMut];
+}
+
+impl_index! {
+    impl[S] FlattenUniform<S> where [S: SliceBorrowed, S::Output: SliceBorrowed]
+        mut [S: SliceBorrowed + SliceMut, S::Output: SliceBorrowed + SliceMut];
+}
+
+#[cfg(feature = "alloc")]
+impl_index! {
+    impl[S, J] Join<S, J> where [
+        S: SliceBorrowed,
+        S::Output: SliceBorrowed,
+        J: SliceBorrowed<Output = <S::Output as Slice>::Output>,
+    ] mut [
+        S: SliceBorrowed + SliceMut,
+        S::Output: SliceBorrowed + SliceMut,
+        J: SliceBorrowed<Output = <S::Output as Slice>::Output> + SliceMut,
+    ];
+}
+
+impl_index! {
+    impl[C] InterleaveN<C> where [C: ContiguousBorrowed, C::Output: SliceBorrowed]
+        mut [C: ContiguousBorrowed + ContiguousMut, C::Output: SliceBorrowed + SliceMut];
+}
+
+impl_index! {
+    impl[S, I] Gather<S, I> where [S: SliceBorrowed, I: SliceOwned<Output = usize>]
+        mut [S: SliceBorrowed + SliceMut, I: SliceOwned<Output = usize>];
+    impl[S, I] Permute<S, I> where [S: SliceBorrowed, I: ContiguousBorrowed<Output = usize>]
+        mut [S: SliceBorrowed + SliceMut, I: ContiguousBorrowed<Output = usize>];
+}
+
+impl_index! {
+    impl[S, F, U] MapRef<S, F> where [S: SliceBorrowed, F: Fn(&S::Output) -> &U];
+}
+
+impl_index! {
+    impl[S, F, G, U] MapMut<S, F, G> where [
+        S: SliceBorrowed,
+        F: Fn(&S::Output) -> &U,
+        G: Fn(&mut S::Output) -> &mut U,
+    ] mut [
+        S: SliceBorrowed + SliceMut,
+        F: Fn(&S::Output) -> &U,
+        G: Fn(&mut S::Output) -> &mut U,
+    ];
 }
@@ -1,6 +1,12 @@
-use crate::{Slice, SliceBorrowed, SliceMut, SliceOwned, Unique};
+use crate::{
+    ContiguousBorrowed, ContiguousMut, RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned,
+    SliceRawMut, Unique,
+};
 
 /// Two interleaved slices; see [`Slice::interleave`].
+///
+/// Ends as soon as the slice whose turn it is runs out.
+#[derive(Clone, Copy)]
 pub struct Interleave<S1, S2>(pub S1, pub S2);
 
 impl<S1, S2> Slice for Interleave<S1, S2>
@@ -11,11 +17,13 @@ where
     type Output = S1::Output;
 
     fn len(&self) -> usize {
-        self.0.len() + self.1.len()
+        interleave_len(self.0.len(), self.1.len())
     }
 
     fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
-        if index % 2 == 0 {
+        if index >= self.len() {
+            None
+        } else if index.is_multiple_of(2) {
             self.0.get_with(index / 2, f)
         } else {
             self.1.get_with(index / 2, f)
@@ -29,7 +37,9 @@ where
     S2: SliceOwned<Output = S1::Output>,
 {
     fn get_owned(&self, index: usize) -> Option<Self::Output> {
-        if index % 2 == 0 {
+        if index >= self.len() {
+            None
+        } else if index.is_multiple_of(2) {
             self.0.get_owned(index / 2)
         } else {
             self.1.get_owned(index / 2)
@@ -43,7 +53,9 @@ where
     S2: SliceBorrowed<Output = S1::Output>,
 {
     fn get(&self, index: usize) -> Option<&Self::Output> {
-        if index % 2 == 0 {
+        if index >= self.len() {
+            None
+        } else if index.is_multiple_of(2) {
             self.0.get(index / 2)
         } else {
             self.1.get(index / 2)
@@ -57,7 +69,9 @@ where
     S2: SliceMut<Output = S1::Output>,
 {
     fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
-        if index % 2 == 0 {
+        if index >= self.len() {
+            None
+        } else if index.is_multiple_of(2) {
             self.0.get_mut(index / 2)
         } else {
             self.1.get_mut(index / 2)
@@ -73,3 +87,544 @@ where
     S2: Unique,
 {
 }
+
+// SAFETY: each side is only reached through its handle
+unsafe impl<S1, S2> SliceRawMut for Interleave<S1, S2>
+where
+    S1: SliceRawMut,
+    S2: SliceRawMut<Output = S1::Output>,
+{
+    type Raw = Interleave<S1::Raw, S2::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            Interleave(
+                S1::raw_mut(&raw mut (*this).0),
+                S2::raw_mut(&raw mut (*this).1),
+            )
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and both sides
+// uphold the rest
+unsafe impl<R1, R2> RawSlice for Interleave<R1, R2>
+where
+    R1: RawSlice,
+    R2: RawSlice<Output = R1::Output>,
+{
+    type Output = R1::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller
+        unsafe { interleave_len(self.0.len(), self.1.len()) }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            if index >= self.len() {
+                None
+            } else if index.is_multiple_of(2) {
+                self.0.get_mut(index / 2)
+            } else {
+                self.1.get_mut(index / 2)
+            }
+        }
+    }
+}
+
+/// The length of two slices of lengths `a` and `b` interleaved, ending as soon
+/// as the one whose turn it is runs out.
+fn interleave_len(a: usize, b: usize) -> usize {
+    if a > b {
+        b.saturating_mul(2).saturating_add(1)
+    } else {
+        a.saturating_mul(2)
+    }
+}
+
+/// Two interleaved slices, continuing with the longer one once the other runs
+/// out; see [`Slice::interleave_longest`].
+#[derive(Clone, Copy)]
+pub struct InterleaveLongest<S1, S2>(pub S1, pub S2);
+
+impl<S1, S2> InterleaveLongest<S1, S2>
+where
+    S1: Slice,
+    S2: Slice<Output = S1::Output>,
+{
+    /// Maps an index to either side, as `Ok` for the first and `Err` for the
+    /// second.
+    fn translate(&self, index: usize) -> Option<Result<usize, usize>> {
+        translate_longest(self.0.len(), self.1.len(), index)
+    }
+}
+
+/// Maps an index into two slices of lengths `a` and `b` interleaved, then
+/// continuing with the longer one, to either side, as `Ok` for the first and
+/// `Err` for the second.
+fn translate_longest(a: usize, b: usize, index: usize) -> Option<Result<usize, usize>> {
+    let shared = a.min(b).saturating_mul(2);
+    if index >= a.saturating_add(b) {
+        None
+    } else if index < shared {
+        if index.is_multiple_of(2) {
+            Some(Ok(index / 2))
+        } else {
+            Some(Err(index / 2))
+        }
+    } else if a > b {
+        Some(Ok(index - b))
+    } else {
+        Some(Err(index - a))
+    }
+}
+
+impl<S1, S2> Slice for InterleaveLongest<S1, S2>
+where
+    S1: Slice,
+    S2: Slice<Output = S1::Output>,
+{
+    type Output = S1::Output;
+
+    fn len(&self) -> usize {
+        self.0.len().saturating_add(self.1.len())
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        match self.translate(index)? {
+            Ok(i) => self.0.get_with(i, f),
+            Err(i) => self.1.get_with(i, f),
+        }
+    }
+}
+
+impl<S1, S2> SliceOwned for InterleaveLongest<S1, S2>
+where
+    S1: SliceOwned,
+    S2: SliceOwned<Output = S1::Output>,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        match self.translate(index)? {
+            Ok(i) => self.0.get_owned(i),
+            Err(i) => self.1.get_owned(i),
+        }
+    }
+}
+
+impl<S1, S2> SliceBorrowed for InterleaveLongest<S1, S2>
+where
+    S1: SliceBorrowed,
+    S2: SliceBorrowed<Output = S1::Output>,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        match self.translate(index)? {
+            Ok(i) => self.0.get(i),
+            Err(i) => self.1.get(i),
+        }
+    }
+}
+
+impl<S1, S2> SliceMut for InterleaveLongest<S1, S2>
+where
+    S1: SliceMut,
+    S2: SliceMut<Output = S1::Output>,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        match self.translate(index)? {
+            Ok(i) => self.0.get_mut(i),
+            Err(i) => self.1.get_mut(i),
+        }
+    }
+}
+
+// SAFETY: both slices are `Unique`, and each index maps to a distinct index of
+// one of them
+unsafe impl<S1, S2> Unique for InterleaveLongest<S1, S2>
+where
+    S1: Unique,
+    S2: Unique,
+{
+}
+
+// SAFETY: each side is only reached through its handle
+unsafe impl<S1, S2> SliceRawMut for InterleaveLongest<S1, S2>
+where
+    S1: SliceRawMut,
+    S2: SliceRawMut<Output = S1::Output>,
+{
+    type Raw = InterleaveLongest<S1::Raw, S2::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            InterleaveLongest(
+                S1::raw_mut(&raw mut (*this).0),
+                S2::raw_mut(&raw mut (*this).1),
+            )
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and both sides
+// uphold the rest
+unsafe impl<R1, R2> RawSlice for InterleaveLongest<R1, R2>
+where
+    R1: RawSlice,
+    R2: RawSlice<Output = R1::Output>,
+{
+    type Output = R1::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller
+        unsafe { self.0.len().saturating_add(self.1.len()) }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            match translate_longest(self.0.len(), self.1.len(), index)? {
+                Ok(i) => self.0.get_mut(i),
+                Err(i) => self.1.get_mut(i),
+            }
+        }
+    }
+}
+
+/// Two slices interleaved in runs, e.g. [A, A, B, A, A, B, ...]; see
+/// [`Slice::interleave_ratio`].
+///
+/// Ends as soon as the slice whose turn it is runs out.
+#[derive(Clone, Copy)]
+pub struct InterleaveRatio<S1, S2> {
+    data: (S1, S2),
+    ratio: (usize, usize),
+}
+
+impl<S1, S2> InterleaveRatio<S1, S2>
+where
+    S1: Slice,
+    S2: Slice<Output = S1::Output>,
+{
+    /// Creates a slice taking `ra` items from `a`, then `rb` from `b`, and so
+    /// on; see [`Slice::interleave_ratio`].
+    ///
+    /// # Panics
+    ///
+    /// If `ra == 0` or `rb == 0`, panics.
+    pub fn new(a: S1, b: S2, ra: usize, rb: usize) -> Self {
+        if ra == 0 || rb == 0 {
+            panic!("cannot call `interleave_ratio` with a ratio of 0");
+        }
+
+        Self {
+            data: (a, b),
+            ratio: (ra, rb),
+        }
+    }
+
+    /// Maps an index to either side, as `Ok` for the first and `Err` for the
+    /// second.
+    fn translate(&self, index: usize) -> Option<Result<usize, usize>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        Some(translate_ratio(self.ratio, index))
+    }
+}
+
+/// Maps an in-bounds index into two slices interleaved in runs of `ra` and
+/// `rb` items to either side, as `Ok` for the first and `Err` for the second.
+fn translate_ratio((ra, rb): (usize, usize), index: usize) -> Result<usize, usize> {
+    let total = ra.saturating_add(rb);
+    let (run, offset) = (index / total, index % total);
+    if offset < ra {
+        Ok(run * ra + offset)
+    } else {
+        Err(run * rb + offset - ra)
+    }
+}
+
+/// The length of two slices of lengths `a` and `b` interleaved in runs of `ra`
+/// and `rb` items, ending as soon as the one whose turn it is runs out.
+fn ratio_len(a: usize, b: usize, (ra, rb): (usize, usize)) -> usize {
+    let runs = (a / ra).min(b / rb);
+
+    let full = runs.saturating_mul(ra.saturating_add(rb));
+
+    let rest_a = a - runs * ra;
+    if rest_a < ra {
+        full.saturating_add(rest_a)
+    } else {
+        full.saturating_add(ra).saturating_add(b - runs * rb)
+    }
+}
+
+impl<S1, S2> Slice for InterleaveRatio<S1, S2>
+where
+    S1: Slice,
+    S2: Slice<Output = S1::Output>,
+{
+    type Output = S1::Output;
+
+    fn len(&self) -> usize {
+        ratio_len(self.data.0.len(), self.data.1.len(), self.ratio)
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        match self.translate(index)? {
+            Ok(i) => self.data.0.get_with(i, f),
+            Err(i) => self.data.1.get_with(i, f),
+        }
+    }
+}
+
+impl<S1, S2> SliceOwned for InterleaveRatio<S1, S2>
+where
+    S1: SliceOwned,
+    S2: SliceOwned<Output = S1::Output>,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        match self.translate(index)? {
+            Ok(i) => self.data.0.get_owned(i),
+            Err(i) => self.data.1.get_owned(i),
+        }
+    }
+}
+
+impl<S1, S2> SliceBorrowed for InterleaveRatio<S1, S2>
+where
+    S1: SliceBorrowed,
+    S2: SliceBorrowed<Output = S1::Output>,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        match self.translate(index)? {
+            Ok(i) => self.data.0.get(i),
+            Err(i) => self.data.1.get(i),
+        }
+    }
+}
+
+impl<S1, S2> SliceMut for InterleaveRatio<S1, S2>
+where
+    S1: SliceMut,
+    S2: SliceMut<Output = S1::Output>,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        match self.translate(index)? {
+            Ok(i) => self.data.0.get_mut(i),
+            Err(i) => self.data.1.get_mut(i),
+        }
+    }
+}
+
+// SAFETY: both slices are `Unique`, and each index maps to a distinct index of
+// one of them
+unsafe impl<S1, S2> Unique for InterleaveRatio<S1, S2>
+where
+    S1: Unique,
+    S2: Unique,
+{
+}
+
+// SAFETY: each side is only reached through its handle
+unsafe impl<S1, S2> SliceRawMut for InterleaveRatio<S1, S2>
+where
+    S1: SliceRawMut,
+    S2: SliceRawMut<Output = S1::Output>,
+{
+    type Raw = InterleaveRatio<S1::Raw, S2::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            InterleaveRatio {
+                data: (
+                    S1::raw_mut(&raw mut (*this).data.0),
+                    S2::raw_mut(&raw mut (*this).data.1),
+                ),
+                ratio: (*this).ratio,
+            }
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and both sides
+// uphold the rest
+unsafe impl<R1, R2> RawSlice for InterleaveRatio<R1, R2>
+where
+    R1: RawSlice,
+    R2: RawSlice<Output = R1::Output>,
+{
+    type Output = R1::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller
+        unsafe { ratio_len(self.data.0.len(), self.data.1.len(), self.ratio) }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            if index >= self.len() {
+                None
+            } else {
+                match translate_ratio(self.ratio, index) {
+                    Ok(i) => self.data.0.get_mut(i),
+                    Err(i) => self.data.1.get_mut(i),
+                }
+            }
+        }
+    }
+}
+
+/// Any number of slices interleaved round-robin; see
+/// [`interleave_n`](crate::interleave_n).
+///
+/// Ends as soon as the slice whose turn it is runs out.
+#[derive(Clone, Copy, Hash)]
+pub struct InterleaveN<C> {
+    slices: C,
+    len: usize,
+}
+
+impl<C> InterleaveN<C>
+where
+    C: ContiguousBorrowed,
+    C::Output: Slice,
+{
+    /// Creates an interleaved slice; see
+    /// [`interleave_n`](crate::interleave_n).
+    pub fn new(slices: C) -> Self {
+        let lens = slices.contiguous().iter().map(Slice::len);
+        let shortest = lens.clone().min().unwrap_or(0);
+        let len = slices
+            .contiguous()
+            .len()
+            .saturating_mul(shortest)
+            .saturating_add(lens.take_while(|&l| l > shortest).count());
+
+        Self { slices, len }
+    }
+
+    fn translate(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len {
+            None
+        } else {
+            let n = self.slices.contiguous().len();
+            Some((index % n, index / n))
+        }
+    }
+
+    /// Returns the interleaved slices.
+    pub fn into_inner(self) -> C {
+        self.slices
+    }
+}
+
+impl<C> Slice for InterleaveN<C>
+where
+    C: ContiguousBorrowed,
+    C::Output: Slice,
+{
+    type Output = <C::Output as Slice>::Output;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        let (n, i) = self.translate(index)?;
+        self.slices.contiguous()[n].get_with(i, f)
+    }
+}
+
+impl<C> SliceOwned for InterleaveN<C>
+where
+    C: ContiguousBorrowed,
+    C::Output: SliceOwned,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        let (n, i) = self.translate(index)?;
+        self.slices.contiguous()[n].get_owned(i)
+    }
+}
+
+impl<C> SliceBorrowed for InterleaveN<C>
+where
+    C: ContiguousBorrowed,
+    C::Output: SliceBorrowed,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        let (n, i) = self.translate(index)?;
+        self.slices.contiguous()[n].get(i)
+    }
+}
+
+impl<C> SliceMut for InterleaveN<C>
+where
+    C: ContiguousBorrowed + ContiguousMut,
+    C::Output: SliceMut,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        let (n, i) = self.translate(index)?;
+        self.slices.contiguous_mut()[n].get_mut(i)
+    }
+}
+
+// SAFETY: the slices are stored contiguously, so are distinct, and each is
+// `Unique`; each index maps to a distinct index of one of them
+unsafe impl<C> Unique for InterleaveN<C>
+where
+    C: ContiguousBorrowed,
+    C::Output: Unique,
+{
+}
+
+// SAFETY: the slices are only reached through their handle
+unsafe impl<C> SliceRawMut for InterleaveN<C>
+where
+    C: ContiguousBorrowed + ContiguousMut + SliceRawMut,
+    C::Output: SliceRawMut,
+{
+    type Raw = InterleaveN<C::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            InterleaveN {
+                slices: C::raw_mut(&raw mut (*this).slices),
+                len: (*this).len,
+            }
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and each slice is
+// reached through its own handle
+unsafe impl<R> RawSlice for InterleaveN<R>
+where
+    R: RawSlice,
+    R::Output: SliceRawMut,
+{
+    type Output = <R::Output as Slice>::Output;
+
+    unsafe fn len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        if index >= self.len {
+            None
+        } else {
+            // SAFETY: guaranteed by the caller
+            unsafe {
+                let n = self.slices.len();
+                R::Output::raw_mut(self.slices.get_mut(index % n)?).get_mut(index / n)
+            }
+        }
+    }
+}
@@ -24,6 +24,29 @@ where
     }
 }
 
+impl<S> IterOwned<S>
+where
+    S: Clone,
+{
+    /// Splits the remaining items in two, at `index` from the front.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.start + index;
+        (
+            Self {
+                data: self.data.clone(),
+                start: self.start,
+                end: mid,
+            },
+            Self {
+                data: self.data,
+                start: mid,
+                end: self.end,
+            },
+        )
+    }
+}
+
 impl<S> Iterator for IterOwned<S>
 where
     S: SliceOwned,
@@ -41,7 +64,8 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.data.len(), Some(self.data.len()))
+        let len = self.end - self.start;
+        (len, Some(len))
     }
 }
 
@@ -87,6 +111,29 @@ where
     }
 }
 
+impl<S> IterBorrowed<'_, S>
+where
+    S: ?Sized,
+{
+    /// Splits the remaining items in two, at `index` from the front.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.start + index;
+        (
+            Self {
+                data: self.data,
+                start: self.start,
+                end: mid,
+            },
+            Self {
+                data: self.data,
+                start: mid,
+                end: self.end,
+            },
+        )
+    }
+}
+
 impl<'a, S> Iterator for IterBorrowed<'a, S>
 where
     S: SliceBorrowed + ?Sized,
@@ -104,13 +151,14 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.data.len(), Some(self.data.len()))
+        let len = self.end - self.start;
+        (len, Some(len))
     }
 }
 
 impl<'a, S> DoubleEndedIterator for IterBorrowed<'a, S>
 where
-    S: SliceBorrowed,
+    S: SliceBorrowed + ?Sized,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.start == self.end {
@@ -123,4 +171,4 @@ where
     }
 }
 
-impl<'a, S> ExactSizeIterator for IterBorrowed<'a, S> where S: SliceBorrowed {}
+impl<'a, S> ExactSizeIterator for IterBorrowed<'a, S> where S: SliceBorrowed + ?Sized {}
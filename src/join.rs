@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+
+use crate::{Slice, SliceBorrowed, SliceMut, SliceOwned};
+
+/// The part of a joined slice an index falls in.
+enum Part {
+    Item(usize, usize),
+    Separator(usize),
+}
+
+/// A slice of slices joined into one, with a separator between each; see
+/// [`Slice::join_with`]. Only available on feature `alloc`.
+///
+/// Every index past the first inner slice may be in the separator, so this is
+/// never [`Unique`](crate::Unique).
+#[derive(Clone, Hash)]
+pub struct Join<S, J> {
+    data: S,
+    separator: J,
+    ends: Vec<usize>,
+}
+
+impl<S, J> Join<S, J>
+where
+    S: Slice,
+    S::Output: Slice,
+    J: Slice<Output = <S::Output as Slice>::Output>,
+{
+    /// Creates a joined slice, measuring each inner slice up-front; see
+    /// [`Slice::join_with`].
+    pub fn new(data: S, separator: J) -> Self {
+        let mut ends = Vec::with_capacity(data.len());
+        let mut end = 0_usize;
+        for i in 0..data.len() {
+            if i > 0 {
+                end = end.saturating_add(separator.len());
+            }
+            let len = data.get_with(i, &mut |x| x.len()).unwrap_or(0);
+            end = end.saturating_add(len);
+            ends.push(end);
+        }
+
+        Self {
+            data,
+            separator,
+            ends,
+        }
+    }
+
+    fn translate(&self, index: usize) -> Option<Part> {
+        let n = self.ends.partition_point(|&end| end <= index);
+        if n == self.ends.len() {
+            None
+        } else if n == 0 {
+            Some(Part::Item(0, index))
+        } else {
+            let prev = self.ends[n - 1];
+            let start = prev + self.separator.len();
+            if index < start {
+                Some(Part::Separator(index - prev))
+            } else {
+                Some(Part::Item(n, index - start))
+            }
+        }
+    }
+}
+
+impl<S, J> Slice for Join<S, J>
+where
+    S: Slice,
+    S::Output: Slice,
+    J: Slice<Output = <S::Output as Slice>::Output>,
+{
+    type Output = <S::Output as Slice>::Output;
+
+    fn len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        match self.translate(index)? {
+            Part::Item(outer, inner) => self
+                .data
+                .get_with(outer, &mut |x| x.get_with(inner, f))
+                .flatten(),
+            Part::Separator(i) => self.separator.get_with(i, f),
+        }
+    }
+}
+
+impl<S, J> SliceOwned for Join<S, J>
+where
+    S: Slice,
+    S::Output: SliceOwned,
+    J: SliceOwned<Output = <S::Output as Slice>::Output>,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        match self.translate(index)? {
+            Part::Item(outer, inner) => self
+                .data
+                .get_with(outer, &mut |x| x.get_owned(inner))
+                .flatten(),
+            Part::Separator(i) => self.separator.get_owned(i),
+        }
+    }
+}
+
+impl<S, J> SliceBorrowed for Join<S, J>
+where
+    S: SliceBorrowed,
+    S::Output: SliceBorrowed,
+    J: SliceBorrowed<Output = <S::Output as Slice>::Output>,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        match self.translate(index)? {
+            Part::Item(outer, inner) => self.data.get(outer)?.get(inner),
+            Part::Separator(i) => self.separator.get(i),
+        }
+    }
+}
+
+impl<S, J> SliceMut for Join<S, J>
+where
+    S: SliceMut,
+    S::Output: SliceMut,
+    J: SliceMut<Output = <S::Output as Slice>::Output>,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        match self.translate(index)? {
+            Part::Item(outer, inner) => self.data.get_mut(outer)?.get_mut(inner),
+            Part::Separator(i) => self.separator.get_mut(i),
+        }
+    }
+}
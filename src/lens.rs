@@ -0,0 +1,57 @@
+use crate::{Slice, SliceMut, SliceOwned};
+
+/// A computed view that can be written through; see [`SliceMut::lens`].
+#[derive(Clone, Copy, Hash)]
+pub struct Lens<S, G, P>(pub S, pub G, pub P);
+
+impl<S, G, P, U> Lens<S, G, P>
+where
+    S: SliceMut,
+    G: Fn(&S::Output) -> U,
+    P: Fn(&mut S::Output, U),
+{
+    /// Write a value through the lens, returning `None` if the index was
+    /// out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceMut;
+    /// let mut pairs = [(1, 'a'), (2, 'b')];
+    /// let mut lens = (&mut pairs).lens(|p| p.1, |p, c| p.1 = c);
+    ///
+    /// assert_eq!(lens.set(1, 'z'), Some(()));
+    /// assert_eq!(lens.set(2, 'z'), None);
+    /// assert_eq!(pairs, [(1, 'a'), (2, 'z')]);
+    /// ```
+    pub fn set(&mut self, index: usize, value: U) -> Option<()> {
+        (self.2)(self.0.get_mut(index)?, value);
+        Some(())
+    }
+}
+
+impl<S, G, P, U> Slice for Lens<S, G, P>
+where
+    S: Slice,
+    G: Fn(&S::Output) -> U,
+{
+    type Output = U;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        Some(f(&self.get_owned(index)?))
+    }
+}
+
+impl<S, G, P, U> SliceOwned for Lens<S, G, P>
+where
+    S: Slice,
+    G: Fn(&S::Output) -> U,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        self.0.get_with(index, &mut |x| (self.1)(x))
+    }
+}
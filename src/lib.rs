@@ -6,34 +6,70 @@ mod chain;
 mod chunks;
 mod cycle;
 mod debug;
+mod enumerate;
 mod eq;
+mod flatten;
 mod fromfn;
+mod gather;
 mod impls;
 mod index;
 mod interleave;
 mod iter;
+#[cfg(feature = "alloc")]
+mod join;
+mod lens;
+mod macros;
 mod map;
+#[cfg(feature = "rayon")]
+mod par;
+mod product;
 mod reverse;
+mod rotate;
 mod slicing;
+mod sort;
+mod stepby;
+mod unzip;
 mod windows;
 mod zip;
 
 #[cfg(test)]
 mod test;
 
-use core::ops::RangeBounds;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-pub use chain::Chain;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::mem::{self, ManuallyDrop};
+use core::ops::{Bound, RangeBounds};
+use core::ptr;
+
+pub use chain::{Chain, ChainMany};
 pub use chunks::{ArrayChunksBorrowed, ArrayChunksOwned, ChunksBorrowed, ChunksOwned};
 pub use cycle::Cycle;
+pub use enumerate::{EnumerateBorrowed, EnumerateOwned};
+#[cfg(feature = "alloc")]
+pub use flatten::Flatten;
+pub use flatten::FlattenUniform;
 pub use fromfn::FromFn;
-pub use interleave::Interleave;
+pub use gather::{Gather, Permute};
+pub use interleave::{Interleave, InterleaveLongest, InterleaveN, InterleaveRatio};
 pub use iter::{IterBorrowed, IterOwned};
-pub use map::{MapBorrowed, MapOwned};
+#[cfg(feature = "alloc")]
+pub use join::Join;
+pub use lens::Lens;
+pub use map::{MapBorrowed, MapMut, MapOwned, MapRef, MapWithIndexBorrowed, MapWithIndexOwned};
+#[cfg(feature = "rayon")]
+pub use par::{ParChunksMut, ParIterBorrowed, ParIterOwned};
+pub use product::Product;
 pub use reverse::Reverse;
-pub use slicing::{SliceOf, SplitMut};
+pub use rotate::Rotate;
+pub use slicing::{Lane, SliceOf, SplitMut};
+pub use stepby::StepBy;
+pub use unzip::{Pair, UnzipLeft, UnzipLeftMut, UnzipRight, UnzipRightMut};
 pub use windows::{ArrayWindowsBorrowed, ArrayWindowsOwned, WindowsBorrowed, WindowsOwned};
-pub use zip::Zip;
+pub use zip::{Zip, Zip3, Zip4, ZipBorrowed, ZipIterMut, ZipMut};
 
 /// Clones each item on access; see [`SliceBorrowed::cloned`].
 pub type Cloned<S> = MapBorrowed<S, for<'a> fn(&<S as Slice>::Output) -> <S as Slice>::Output>;
@@ -78,6 +114,84 @@ pub trait Slice {
     /// ```
     fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R>;
 
+    /// Binary searches a sorted slice for an element.
+    ///
+    /// Returns `Ok` with the index of a matching element, or `Err` with the
+    /// index where it could be inserted while maintaining sorted order. If
+    /// there are multiple matches, any one of them may be returned.
+    ///
+    /// Analagous to [`slice::binary_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// let slice = [1, 3, 5].chain([7, 9]);
+    ///
+    /// assert_eq!(slice.binary_search(&7), Ok(3));
+    /// assert_eq!(slice.binary_search(&4), Err(2));
+    /// assert_eq!(slice.binary_search(&10), Err(5));
+    /// ```
+    fn binary_search(&self, x: &Self::Output) -> Result<usize, usize>
+    where
+        Self::Output: Ord,
+    {
+        self.binary_search_by(|y| y.cmp(x))
+    }
+
+    /// Binary searches a sorted slice with a comparator function.
+    ///
+    /// The comparator should return the ordering of each element relative to
+    /// the target. Elements that can't be retrieved, e.g. when a
+    /// [`from_fn`] slice returns `None`, are treated as greater than the
+    /// target. See [`Slice::binary_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// // Find the integer square root of 300
+    /// let squares = slice_utils::from_fn(|i| i.checked_mul(i), None);
+    /// let root = squares.binary_search_by(|x| x.cmp(&300)).unwrap_or_else(|i| i - 1);
+    ///
+    /// assert_eq!(root, 17);
+    /// ```
+    fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&Self::Output) -> Ordering,
+    {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.get_with(mid, &mut f).unwrap_or(Ordering::Greater) {
+                Ordering::Less => lo = mid + 1,
+                Ordering::Equal => return Ok(mid),
+                Ordering::Greater => hi = mid,
+            }
+        }
+
+        Err(lo)
+    }
+
+    /// Binary searches a sorted slice with a key extraction function.
+    ///
+    /// See [`Slice::binary_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// let slice = [(1, 'a'), (4, 'b'), (9, 'c')].rev();
+    /// assert_eq!(slice.binary_search_by_key(&-4, |x| -x.0), Ok(1));
+    /// ```
+    fn binary_search_by_key<B, F>(&self, b: &B, mut f: F) -> Result<usize, usize>
+    where
+        B: Ord,
+        F: FnMut(&Self::Output) -> B,
+    {
+        self.binary_search_by(|x| f(x).cmp(b))
+    }
+
     /// Chains two slices together, back-to-back.
     ///
     /// Analagous to [`Iterator::chain`].
@@ -118,8 +232,91 @@ pub trait Slice {
         Cycle(self)
     }
 
+    /// Flattens a slice of slices into one. Only available on feature
+    /// `alloc`.
+    ///
+    /// The length of every inner slice is stored up-front, so indexing is
+    /// `O(log n)` in the number of inner slices. If they all have the same
+    /// length, [`Slice::flatten_uniform`] is `O(1)` and doesn't allocate.
+    ///
+    /// Analagous to [`Iterator::flatten`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut rows = vec![vec![1, 2], vec![], vec![3, 4, 5]];
+    /// let mut flat = (&mut rows).flatten();
+    ///
+    /// assert_eq!(flat, [1, 2, 3, 4, 5]);
+    /// *flat.get_mut(2).unwrap() = 0;
+    /// assert_eq!(rows[2], [0, 4, 5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+        Self::Output: Slice,
+    {
+        Flatten::new(self)
+    }
+
+    /// Flattens a slice of slices which all have at least `width` items into
+    /// one.
+    ///
+    /// Inner items past `width` are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Inner slices aren't measured up-front, so the outer slice may be
+    /// infinite; instead, reaching into an inner slice shorter than `width`
+    /// panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceBorrowed};
+    /// let pixels = [[0.0, 0.1, 0.2, 1.0], [0.3, 0.4, 0.5, 1.0]];
+    /// let channels = pixels.flatten_uniform(4);
+    ///
+    /// assert_eq!(channels.len(), 8);
+    /// assert_eq!(channels.get(5), Some(&0.4));
+    /// ```
+    fn flatten_uniform(self, width: usize) -> FlattenUniform<Self>
+    where
+        Self: Sized,
+        Self::Output: Slice,
+    {
+        FlattenUniform::new(self, width)
+    }
+
+    /// Creates a slice that reads through a slice of indices, i.e.
+    /// `gathered[i] == self[indices[i]]`.
+    ///
+    /// Indices may repeat or be left out. For a [`Unique`] reordering, see
+    /// [`Slice::permute`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// let slice = [10, 20, 30, 40];
+    /// let gathered = slice.gather([3, 0, 0, 2]);
+    ///
+    /// assert_eq!(gathered, [40, 10, 10, 30]);
+    /// ```
+    fn gather<I: SliceOwned<Output = usize>>(self, indices: I) -> Gather<Self, I>
+    where
+        Self: Sized,
+    {
+        Gather(self, indices)
+    }
+
     /// Interleaves two slices, e.g. [A, B, A, B, ...].
     ///
+    /// The result ends as soon as the slice whose turn it is runs out; to
+    /// keep the rest of the longer slice, see [`Slice::interleave_longest`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -129,6 +326,7 @@ pub trait Slice {
     /// let c = a.interleave(b);
     ///
     /// assert_eq!(c, [1, 4, 2, 5, 3, 6]);
+    /// assert_eq!([1, 2, 3].interleave([4]), [1, 4, 2]);
     /// ```
     fn interleave<S: Slice<Output = Self::Output>>(self, other: S) -> Interleave<Self, S>
     where
@@ -137,6 +335,141 @@ pub trait Slice {
         Interleave(self, other)
     }
 
+    /// Interleaves two slices, e.g. [A, B, A, B, ...], then continues with the
+    /// rest of the longer one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// let a = [1, 2, 3, 4];
+    /// let b = [5];
+    /// let c = a.interleave_longest(b);
+    ///
+    /// assert_eq!(c, [1, 5, 2, 3, 4]);
+    /// ```
+    fn interleave_longest<S>(self, other: S) -> InterleaveLongest<Self, S>
+    where
+        Self: Sized,
+        S: Slice<Output = Self::Output>,
+    {
+        InterleaveLongest(self, other)
+    }
+
+    /// Interleaves two slices in runs of `ra` and `rb` items, e.g.
+    /// [A, A, B, A, A, B, ...] for a ratio of 2 to 1.
+    ///
+    /// The result ends as soon as the slice whose turn it is runs out.
+    ///
+    /// # Panics
+    ///
+    /// If `ra == 0` or `rb == 0`, panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// let video = ['v'; 5];
+    /// let audio = ['a'; 5];
+    /// let muxed = video.interleave_ratio(audio, 2, 1);
+    ///
+    /// assert_eq!(muxed, ['v', 'v', 'a', 'v', 'v', 'a', 'v']);
+    /// ```
+    fn interleave_ratio<S>(self, other: S, ra: usize, rb: usize) -> InterleaveRatio<Self, S>
+    where
+        Self: Sized,
+        S: Slice<Output = Self::Output>,
+    {
+        InterleaveRatio::new(self, other, ra, rb)
+    }
+
+    /// Joins a slice of slices into one, placing `separator` between each.
+    /// Only available on feature `alloc`.
+    ///
+    /// Nothing is copied; the length of every inner slice is stored
+    /// up-front, so indexing is `O(log n)` in the number of inner slices.
+    ///
+    /// Analagous to [`slice::join`], under a different name so that it doesn't
+    /// shadow it on arrays and vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let fields = [&b"GET"[..], b"/index.html", b"HTTP/1.1"];
+    /// let line = fields.join_with(*b" ");
+    ///
+    /// let mut buf = [0; 24];
+    /// buf.copy_from_slice(&line);
+    /// assert_eq!(&buf, b"GET /index.html HTTP/1.1");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn join_with<J>(self, separator: J) -> Join<Self, J>
+    where
+        Self: Sized,
+        Self::Output: Slice,
+        J: Slice<Output = <Self::Output as Slice>::Output>,
+    {
+        Join::new(self, separator)
+    }
+
+    /// Returns the index of the first element for which the predicate is
+    /// false, assuming the slice is partitioned by it (all the `true`
+    /// elements come before all the `false` ones).
+    ///
+    /// Analagous to [`slice::partition_point`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceOwned};
+    /// let slice = [1, 2, 3, 4, 5].map(|x| x * 10);
+    /// assert_eq!(slice.partition_point(|&x| x < 25), 2);
+    /// ```
+    fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&Self::Output) -> bool,
+    {
+        self.binary_search_by(|x| {
+            if pred(x) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_or_else(|i| i)
+    }
+
+    /// Reorders the slice by a permutation of its indices, i.e.
+    /// `permuted[i] == self[indices[i]]`.
+    ///
+    /// Returns `None` if `indices` isn't a permutation of `0..self.len()`.
+    /// Unlike [`Slice::gather`], the result is [`Unique`] (as long as the
+    /// indices are [`StableContiguous`]), so it can be mutably split.
+    ///
+    /// Checking the indices takes `O(n)` time and an `O(n)` allocation on
+    /// feature `alloc`, and `O(n²)` time without it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// let mut data = [10, 20, 30];
+    /// assert!(data.permute([0, 0, 1]).is_none());
+    ///
+    /// let mut slice = (&mut data).permute([2, 0, 1]).unwrap();
+    /// assert_eq!(slice, [30, 10, 20]);
+    ///
+    /// slice[0] = 0;
+    /// assert_eq!(data, [10, 20, 0]);
+    /// ```
+    fn permute<I: ContiguousBorrowed<Output = usize>>(self, indices: I) -> Option<Permute<Self, I>>
+    where
+        Self: Sized,
+    {
+        Permute::new(self, indices)
+    }
+
     /// Reverses the slice.
     ///
     /// Analagous to [`Iterator::rev`].
@@ -155,6 +488,53 @@ pub trait Slice {
         Reverse(self)
     }
 
+    /// Rotates the slice `k` elements to the left, without moving any data.
+    ///
+    /// Index `i` of the new slice refers to index `(i + k) % len` of the old
+    /// one. Analagous to [`slice::rotate_left`]; see also
+    /// [`SliceMut::rotate_left`], which rotates in-place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// let mut data = [1, 2, 3, 4, 5];
+    /// assert_eq!(data.rotated_left(2), [3, 4, 5, 1, 2]);
+    ///
+    /// let mut slice = (&mut data).rotated_left(3);
+    /// slice[0] = 0;
+    /// assert_eq!(data, [1, 2, 3, 0, 5]);
+    /// ```
+    #[must_use]
+    fn rotated_left(self, k: usize) -> Rotate<Self>
+    where
+        Self: Sized,
+    {
+        Rotate::new(self, k)
+    }
+
+    /// Rotates the slice `k` elements to the right, without moving any data.
+    ///
+    /// Analagous to [`slice::rotate_right`]; see also
+    /// [`SliceMut::rotate_right`], which rotates in-place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// let slice = [1, 2, 3, 4, 5].rotated_right(2);
+    /// assert_eq!(slice, [4, 5, 1, 2, 3]);
+    /// ```
+    #[must_use]
+    fn rotated_right(self, k: usize) -> Rotate<Self>
+    where
+        Self: Sized,
+    {
+        let len = self.len();
+        let k = if len == 0 { 0 } else { k % len };
+        Rotate::new(self, len - k)
+    }
+
     /// Create a sub-slice of the slice.
     ///
     /// Analagous to slicing `&[T]`.
@@ -191,6 +571,32 @@ pub trait Slice {
     fn split(&self, at: usize) -> Option<(SliceOf<&Self>, SliceOf<&Self>)> {
         Some((SliceOf::new(self, ..at)?, SliceOf::new(self, at..)?))
     }
+
+    /// Creates a slice over every `step`th element, starting at the first.
+    ///
+    /// Analagous to [`Iterator::step_by`].
+    ///
+    /// # Panics
+    ///
+    /// If `step == 0`, panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::Slice;
+    /// let mut data = [1, 2, 3, 4, 5, 6, 7];
+    /// assert_eq!(data.step_by(3), [1, 4, 7]);
+    ///
+    /// let mut slice = (&mut data).step_by(2);
+    /// slice[1] = 0;
+    /// assert_eq!(data, [1, 2, 0, 4, 5, 6, 7]);
+    /// ```
+    fn step_by(self, step: usize) -> StepBy<Self>
+    where
+        Self: Sized,
+    {
+        StepBy::new(self, step)
+    }
 }
 
 /// A [`Slice`] that can return borrowed values.
@@ -198,6 +604,71 @@ pub trait SliceBorrowed: Slice {
     /// Index the slice, returning a borrowed value.
     fn get(&self, index: usize) -> Option<&Self::Output>;
 
+    /// Returns the indices that would sort the slice. Only available on
+    /// feature `alloc`.
+    ///
+    /// The sort is stable, so equal elements keep their relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceBorrowed};
+    /// let slice = [3, 1, 2].chain([0]);
+    /// assert_eq!(slice.argsort(), [3, 1, 2, 0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argsort(&self) -> Vec<usize>
+    where
+        Self::Output: Ord,
+    {
+        self.argsort_by(Ord::cmp)
+    }
+
+    /// Returns the indices that would sort the slice with a comparator
+    /// function. Only available on feature `alloc`.
+    ///
+    /// See [`SliceBorrowed::argsort`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceBorrowed;
+    /// let slice = [3, 1, 2];
+    /// assert_eq!(slice.argsort_by(|a, b| b.cmp(a)), [0, 2, 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argsort_by<F>(&self, mut compare: F) -> Vec<usize>
+    where
+        F: FnMut(&Self::Output, &Self::Output) -> Ordering,
+    {
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        <[usize]>::sort_by(&mut indices, |&a, &b| {
+            compare(self.get(a).unwrap(), self.get(b).unwrap())
+        });
+        indices
+    }
+
+    /// Returns the indices that would sort the slice with a key extraction
+    /// function. Only available on feature `alloc`.
+    ///
+    /// See [`SliceBorrowed::argsort`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceBorrowed;
+    /// let slice = [-3_i32, 1, -2];
+    /// assert_eq!(slice.argsort_by_key(|x| x.abs()), [1, 2, 0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argsort_by_key<K, F>(&self, mut f: F) -> Vec<usize>
+    where
+        K: Ord,
+        F: FnMut(&Self::Output) -> K,
+    {
+        self.argsort_by(|a, b| f(a).cmp(&f(b)))
+    }
+
     /// Return an iterator over arrays covering consecutive portions of the
     /// slice.
     ///
@@ -219,7 +690,7 @@ pub trait SliceBorrowed: Slice {
     /// assert!(iter.next().is_none());
     /// assert_eq!(iter.remainder(), [5]);
     /// ```
-    fn array_chunks<const N: usize>(&self) -> ArrayChunksBorrowed<Self, N> {
+    fn array_chunks<const N: usize>(&self) -> ArrayChunksBorrowed<'_, Self, N> {
         ArrayChunksBorrowed::new(self)
     }
 
@@ -248,7 +719,7 @@ pub trait SliceBorrowed: Slice {
     /// assert_eq!(windows.get_owned(1), Some([&2, &3, &4]));
     /// # }
     /// ```
-    fn array_windows<const N: usize>(&self) -> ArrayWindowsBorrowed<Self, N> {
+    fn array_windows<const N: usize>(&self) -> ArrayWindowsBorrowed<'_, Self, N> {
         ArrayWindowsBorrowed::new(self)
     }
 
@@ -273,168 +744,1216 @@ pub trait SliceBorrowed: Slice {
     /// assert_eq!(iter.next().unwrap(), [5]);
     /// assert!(iter.next().is_none());
     /// ```
-    fn chunks(&self, size: usize) -> ChunksBorrowed<Self> {
+    fn chunks(&self, size: usize) -> ChunksBorrowed<'_, Self> {
         ChunksBorrowed::new(self, size)
     }
 
-    /// Call a closure on index, returning a new type.
+    /// Call a closure on index, returning a new type.
+    ///
+    /// Analagous to [`Iterator::map`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceBorrowed;
+    /// let slice = [0, 1, 2].map(|x| x != 0);
+    /// assert_eq!(slice, [false, true, true]);
+    /// ```
+    fn map<F: Fn(&Self::Output) -> R, R>(self, f: F) -> MapBorrowed<Self, F>
+    where
+        Self: Sized,
+    {
+        MapBorrowed(self, f)
+    }
+
+    /// Call a closure on each index and item, returning a new type.
+    ///
+    /// Like [`SliceBorrowed::map`], but the closure is also given the index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceBorrowed;
+    /// let slice = [3, 2, 1].map_with_index(|i, x| i * x);
+    /// assert_eq!(slice, [0, 2, 2]);
+    /// ```
+    fn map_with_index<F: Fn(usize, &Self::Output) -> R, R>(
+        self,
+        f: F,
+    ) -> MapWithIndexBorrowed<Self, F>
+    where
+        Self: Sized,
+    {
+        MapWithIndexBorrowed(self, f)
+    }
+
+    /// Project each element to a place inside it, e.g. a field, without
+    /// copying it.
+    ///
+    /// Unlike [`SliceBorrowed::map`], the result is still a
+    /// [`SliceBorrowed`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceBorrowed;
+    /// struct Record {
+    ///     name: String,
+    ///     score: u32,
+    /// }
+    ///
+    /// let records = [
+    ///     Record { name: "a".to_string(), score: 3 },
+    ///     Record { name: "b".to_string(), score: 1 },
+    /// ];
+    ///
+    /// let names = records.map_ref(|r| &r.name);
+    /// assert_eq!(names.get(1), Some(&"b".to_string()));
+    /// assert_eq!(names[0], "a");
+    /// ```
+    fn map_ref<F, U>(self, f: F) -> MapRef<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Output) -> &U,
+    {
+        MapRef(self, f)
+    }
+
+    /// Create a new slice that clones each value on access.
+    /// Analagous to <code>self.[map](SliceBorrowed::map)([Clone::clone])</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceBorrowed;
+    /// # #[derive(Debug, PartialEq)]
+    /// struct Foo(u32);
+    /// impl Clone for Foo {
+    ///     fn clone(&self) -> Foo { Foo(self.0 + 1) }
+    /// }
+    ///
+    /// let slice = [Foo(1), Foo(2)];
+    ///
+    /// assert_eq!(slice.cloned(), [Foo(2), Foo(3)]);
+    /// ```
+    fn cloned(self) -> Cloned<Self>
+    where
+        Self: Sized,
+        Self::Output: Clone,
+    {
+        MapBorrowed(self, Clone::clone)
+    }
+
+    /// Creates a slice returning a reference to each item along with its
+    /// index.
+    ///
+    /// Analagous to [`Iterator::enumerate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceBorrowed};
+    /// let slice = ['a', 'b'].chain(['c']);
+    /// let enumerated = slice.enumerate();
+    ///
+    /// assert_eq!(enumerated, [(0, &'a'), (1, &'b'), (2, &'c')]);
+    /// ```
+    fn enumerate(&self) -> EnumerateBorrowed<'_, Self> {
+        EnumerateBorrowed(self)
+    }
+
+    /// Creates an iterator over the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceBorrowed};
+    /// let slice = [1, 2].chain([3]);
+    /// let mut iter = slice.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert!(iter.next().is_none());
+    /// ```
+    fn iter(&self) -> IterBorrowed<'_, Self> {
+        IterBorrowed::new(self)
+    }
+
+    /// Creates a parallel iterator over the slice, which splits it by index.
+    /// Only available on feature `rayon`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceBorrowed};
+    /// use rayon::prelude::*;
+    ///
+    /// let slice = [1, 2].chain([3, 4]);
+    /// let sum: i32 = slice.par_iter_borrowed().sum();
+    /// assert_eq!(sum, 10);
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn par_iter_borrowed(&self) -> ParIterBorrowed<'_, Self>
+    where
+        Self: Sync,
+        Self::Output: Sync,
+    {
+        ParIterBorrowed(self)
+    }
+
+    /// Creates a sorted view of the slice, without moving any data. Only
+    /// available on feature `alloc`.
+    ///
+    /// Equivalent to <code>self.[permute](Slice::permute)(self.[argsort](SliceBorrowed::argsort)())</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceBorrowed};
+    /// let mut a = [5, 1, 4];
+    /// let mut b = vec![3, 2];
+    ///
+    /// let mut sorted = (&mut a).chain(&mut b).sorted();
+    /// assert_eq!(sorted, [1, 2, 3, 4, 5]);
+    ///
+    /// sorted[0] = 0;
+    /// assert_eq!(a, [5, 0, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sorted(self) -> Permute<Self, Vec<usize>>
+    where
+        Self: Sized,
+        Self::Output: Ord,
+    {
+        let indices = self.argsort();
+
+        // SAFETY: `argsort` always returns a permutation of the indices
+        unsafe { Permute::new_unchecked(self, indices) }
+    }
+
+    /// Split a slice of pairs into two slices, one over each side.
+    ///
+    /// The inverse of [`SliceBorrowed::zip`]. To mutate one side, wrap a
+    /// mutable slice in [`UnzipLeft`] or [`UnzipRight`] directly; to mutate
+    /// both at once, see [`SliceMut::unzip_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceBorrowed;
+    /// let pairs = [(1, 'a'), (2, 'b'), (3, 'c')];
+    /// let (numbers, letters) = pairs.unzip();
+    ///
+    /// assert_eq!(numbers, [1, 2, 3]);
+    /// assert_eq!(letters, ['a', 'b', 'c']);
+    /// ```
+    fn unzip<A, B>(&self) -> (UnzipLeft<&Self>, UnzipRight<&Self>)
+    where
+        Self: SliceBorrowed<Output = (A, B)>,
+    {
+        (UnzipLeft(self), UnzipRight(self))
+    }
+
+    /// Return a slice/iterator over slices covering overlapping portions of the
+    /// slice.
+    ///
+    /// Analagous to [`slice::windows`].
+    ///
+    /// # Panics
+    ///
+    /// If `size == 0`, panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceBorrowed;
+    /// let slice = [1, 2, 3, 4, 5];
+    /// let mut windows = slice.windows(3);
+    ///
+    /// assert_eq!(windows.next().unwrap(), [1, 2, 3]);
+    /// assert_eq!(windows.next().unwrap(), [2, 3, 4]);
+    /// assert_eq!(windows.next().unwrap(), [3, 4, 5]);
+    /// assert!(windows.next().is_none());
+    /// # {
+    /// # use slice_utils::SliceOwned;
+    /// assert_eq!(windows.get_owned(1).unwrap(), [2, 3, 4]);
+    /// # }
+    /// ```
+    fn windows(&self, size: usize) -> WindowsBorrowed<'_, Self> {
+        WindowsBorrowed::new(self, size)
+    }
+
+    /// Zip two borrowed slices into a single slice, where indexing returns a
+    /// tuple of references to their items.
+    ///
+    /// Unlike [`SliceOwned::zip`], the items don't need to be copied out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceBorrowed;
+    /// let names = ["a".to_string(), "b".to_string()];
+    /// let ages = [30, 40, 50];
+    /// let table = names.zip(&ages);
+    ///
+    /// assert_eq!(table, [(&"a".to_string(), &30), (&"b".to_string(), &40)]);
+    /// ```
+    fn zip<'a, O>(&'a self, other: &'a O) -> ZipBorrowed<'a, Self, O>
+    where
+        O: SliceBorrowed + ?Sized,
+    {
+        ZipBorrowed(self, other)
+    }
+}
+
+/// A [`Slice`] that can return mutably borrowed values.
+pub trait SliceMut: Slice {
+    /// Index the slice, returning a mutably borrowed value.
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output>;
+
+    /// Returns the slice as a contiguous `&mut [T]`, if it is one.
+    ///
+    /// This lets methods like [`SliceMut::sort_unstable`] fall through to
+    /// `core`'s algorithms. The default returns `None`; it should only be
+    /// overridden by slices that are contiguous in memory, and by wrappers
+    /// forwarding to one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut data = [1, 2, 3];
+    /// assert!((&mut data).as_contiguous_mut().is_some());
+    /// assert!((&mut data).rev().as_contiguous_mut().is_none());
+    /// ```
+    fn as_contiguous_mut(&mut self) -> Option<&mut [Self::Output]> {
+        None
+    }
+
+    /// Returns `(&mut self[..at], &mut self[at..])`.
+    /// Returns `None` if `at` is out-of-bounds.
+    ///
+    /// Analagous to [`slice::split_mut`].
+    ///
+    /// To avoid aliasing, requires <code>Self: [SliceRawMut]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceMut;
+    /// let mut slice = [1, 2, 3, 4, 5];
+    /// let (mut left, mut right) = SliceMut::split_mut(&mut slice, 2).unwrap();
+    ///
+    /// assert_eq!(left, [1, 2, 3]);
+    /// assert_eq!(right, [4, 5]);
+    ///
+    /// left[0] = 0;
+    /// right[0] = 0;
+    /// assert_eq!(slice, [0, 2, 3, 0, 5]);
+    /// ```
+    fn split_mut(&mut self, at: usize) -> Option<(SplitMut<'_, Self>, SplitMut<'_, Self>)>
+    where
+        Self: SliceRawMut,
+    {
+        SplitMut::new(self, at)
+    }
+
+    /// Splits the slice into `N` disjoint mutable pieces, where each piece
+    /// ends at the matching entry of `at`, i.e.
+    /// `[&mut self[..at[0]], &mut self[at[0]..at[1]], ...]`.
+    ///
+    /// Items after the last split point aren't included; pass `self.len()`
+    /// last to cover the whole slice. Returns `None` if the split points
+    /// aren't in ascending order or are out-of-bounds.
+    ///
+    /// To avoid aliasing, requires <code>Self: [SliceRawMut]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceMut;
+    /// let mut buf = [0; 6];
+    /// let [mut header, mut body, mut footer] = buf.split_many_mut([1, 5, 6]).unwrap();
+    ///
+    /// header.fill(1);
+    /// body.fill(2);
+    /// footer.fill(3);
+    ///
+    /// assert_eq!(buf, [1, 2, 2, 2, 2, 3]);
+    /// ```
+    fn split_many_mut<const N: usize>(&mut self, at: [usize; N]) -> Option<[SplitMut<'_, Self>; N]>
+    where
+        Self: SliceRawMut,
+    {
+        SplitMut::new_many(self, at)
+    }
+
+    /// Returns mutable references to `N` items at once.
+    ///
+    /// Returns `None` if any index is out-of-bounds, or if any two indices are
+    /// the same.
+    ///
+    /// Analagous to [`slice::get_disjoint_mut`].
+    ///
+    /// To avoid aliasing, requires <code>Self: [SliceRawMut]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut slice = [1, 2, 3, 4].rev();
+    /// let [a, b] = slice.get_many_mut([0, 3]).unwrap();
+    /// core::mem::swap(a, b);
+    ///
+    /// assert_eq!(slice, [1, 3, 2, 4]);
+    /// assert!(slice.get_many_mut([1, 1]).is_none());
+    /// assert!(slice.get_many_mut([4]).is_none());
+    /// ```
+    fn get_many_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[&mut Self::Output; N]>
+    where
+        Self: SliceRawMut,
+    {
+        let len = self.len();
+        for (n, &i) in <[usize]>::iter(&indices).enumerate() {
+            if i >= len || indices[..n].contains(&i) {
+                return None;
+            }
+        }
+
+        // SAFETY: `self` is borrowed for as long as the references live, and
+        // is only reached through the handle until then
+        let raw = unsafe { Self::raw_mut(self) };
+        let mut ptrs = [ptr::null_mut(); N];
+        for (ptr, &i) in ptrs.iter_mut().zip(&indices) {
+            // SAFETY: as above
+            *ptr = unsafe { raw.get_mut(i)? };
+        }
+
+        // SAFETY: the indices are distinct, and `Unique` guarantees that two
+        // different indices never alias
+        Some(ptrs.map(|ptr| unsafe { &mut *ptr }))
+    }
+
+    /// Split a slice of pairs into two mutable slices, one over each side.
+    ///
+    /// Unlike [`SliceBorrowed::unzip`], both sides can be mutated at the same
+    /// time, since each only ever borrows its own half of every pair. To avoid
+    /// aliasing, requires <code>Self: [SliceRawMut]</code>, whose handles
+    /// never reference a whole pair; that rules out e.g. [`MapMut`], which
+    /// needs a `&mut` to it:
+    ///
+    /// ```rust,compile_fail
+    /// # use slice_utils::SliceMut;
+    /// let mut records = [((1, 'a'), 0.5)];
+    /// let mut kvs = (&mut records).map_mut(|r| &r.0, |r| &mut r.0);
+    /// let _ = kvs.unzip_mut();
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut pairs = [(1, 'a'), (2, 'b'), (3, 'c')];
+    /// let (mut numbers, mut letters) = pairs.unzip_mut();
+    ///
+    /// numbers.reverse();
+    /// letters.fill('z');
+    ///
+    /// assert_eq!(pairs, [(3, 'z'), (2, 'z'), (1, 'z')]);
+    ///
+    /// let mut reversed = (&mut pairs).rev();
+    /// let (mut numbers, _) = reversed.unzip_mut();
+    /// numbers[0] = 0;
+    ///
+    /// assert_eq!(pairs, [(3, 'z'), (2, 'z'), (0, 'z')]);
+    /// ```
+    fn unzip_mut(&mut self) -> (UnzipLeftMut<'_, Self>, UnzipRightMut<'_, Self>)
+    where
+        Self: SliceRawMut,
+        Self::Output: Pair,
+    {
+        // SAFETY: `self` is borrowed for as long as the views live, and is
+        // only reached through the handle until then; the two views touch
+        // disjoint fields, and the handle never references a whole pair
+        unsafe {
+            let raw = Self::raw_mut(self);
+            (UnzipLeftMut::new(raw), UnzipRightMut::new(raw))
+        }
+    }
+
+    /// Splits the slice into `N` strided lanes, where lane `k` sees the items
+    /// at `k`, `k + N`, `k + 2N`, and so on.
+    ///
+    /// Each lane can be used mutably at the same time as the others, e.g. to
+    /// process one channel of interleaved audio while reading another. To
+    /// avoid aliasing, requires <code>Self: [SliceRawMut]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceMut;
+    /// let mut stereo = [1, -1, 2, -2, 3, -3];
+    /// let [mut left, right] = stereo.deinterleave::<2>();
+    ///
+    /// assert_eq!(right, [-1, -2, -3]);
+    /// left.copy_from_slice(&right);
+    ///
+    /// assert_eq!(stereo, [-1, -1, -2, -2, -3, -3]);
+    /// ```
+    fn deinterleave<const N: usize>(&mut self) -> [Lane<'_, Self>; N]
+    where
+        Self: SliceRawMut,
+    {
+        Lane::new(self)
+    }
+
+    /// Creates a parallel iterator over disjoint mutable chunks of `size`
+    /// items (the last may be shorter). Only available on feature `rayon`;
+    /// see also [`slice_utils::scoped_chunks_mut`](crate::scoped_chunks_mut),
+    /// which only needs `std`.
+    ///
+    /// Named so as not to clash with rayon's own `par_chunks_mut` on
+    /// contiguous slices.
+    ///
+    /// To avoid aliasing, requires <code>Self: [SliceRawMut]</code>.
+    ///
+    /// # Panics
+    ///
+    /// If `size == 0`, panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// use rayon::prelude::*;
+    ///
+    /// let mut a = [1, 2, 3];
+    /// let mut b = [4, 5];
+    /// let mut slice = (&mut a).chain(&mut b);
+    ///
+    /// slice.par_split_chunks_mut(2).for_each(|mut chunk| chunk.reverse());
+    /// assert_eq!((a, b), ([2, 1, 4], [3, 5]));
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn par_split_chunks_mut(&mut self, size: usize) -> ParChunksMut<'_, Self>
+    where
+        Self: SliceRawMut + Sync,
+        Self::Output: Send,
+    {
+        ParChunksMut::new(self, size)
+    }
+
+    /// Copy all the items from `src` into `self`.
+    ///
+    /// Similar to [`slice::clone_from_slice`].
+    ///
+    /// # Panics
+    ///
+    /// The lengths must match. If you only want to copy a sub-slice, you can
+    /// slice each side down to the desired range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut x = [1, 2, 3, 4, 5];
+    /// let y = [6, 7];
+    ///
+    /// (&mut x).slice(3..).unwrap().copy_from_slice(&y);
+    ///
+    /// assert_eq!(x, [1, 2, 3, 6, 7]);
+    /// ```
+    fn copy_from_slice<S>(&mut self, src: &S)
+    where
+        S: Slice<Output = Self::Output>,
+        S::Output: Clone,
+    {
+        if self.len() != src.len() {
+            panic!(
+                "source slice length ({}) does not match destination slice length ({})",
+                src.len(),
+                self.len()
+            )
+        }
+
+        for i in 0..src.len() {
+            src.get_with(i, &mut |item| {
+                *self.get_mut(i).unwrap_or_else(|| {
+                    panic!("destination slice is shorter than source slice");
+                }) = item.clone()
+            });
+        }
+    }
+
+    /// Copies the elements in the range `src` to the position starting at
+    /// `dest`. The two regions may overlap.
+    ///
+    /// Analagous to [`slice::copy_within`], but only requires `Clone`.
+    ///
+    /// # Panics
+    ///
+    /// If either region is out-of-bounds, panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut a = [1, 2, 3];
+    /// let mut b = [4, 5];
+    ///
+    /// (&mut a).chain(&mut b).copy_within(1..4, 0);
+    /// assert_eq!(a, [2, 3, 4]);
+    /// assert_eq!(b, [4, 5]);
+    /// ```
+    fn copy_within<R>(&mut self, src: R, dest: usize)
+    where
+        R: RangeBounds<usize>,
+        Self::Output: Clone,
+    {
+        let len = self.len();
+        let start = match src.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match src.end_bound() {
+            Bound::Included(&e) => e + 1,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => len,
+        };
+
+        if start > end || end > len {
+            panic!("source range {start}..{end} is out of bounds for a slice of length {len}");
+        }
+
+        let count = end - start;
+        if dest > len - count {
+            panic!(
+                "destination {dest}..{} is out of bounds for a slice of length {len}",
+                dest + count
+            );
+        }
+
+        // `slice::copy_within` needs `Copy`, so contiguous slices are still
+        // copied one at a time, just without going through `get_mut`
+        if let Some(slice) = self.as_contiguous_mut() {
+            if dest <= start {
+                for i in 0..count {
+                    slice[dest + i] = slice[start + i].clone();
+                }
+            } else {
+                for i in Iterator::rev(0..count) {
+                    slice[dest + i] = slice[start + i].clone();
+                }
+            }
+            return;
+        }
+
+        let mut copy = |from, to| {
+            let item = self
+                .get_with(from, &mut |x: &Self::Output| x.clone())
+                .unwrap();
+            *self.get_mut(to).unwrap() = item;
+        };
+
+        if dest <= start {
+            for i in 0..count {
+                copy(start + i, dest + i);
+            }
+        } else {
+            for i in Iterator::rev(0..count) {
+                copy(start + i, dest + i);
+            }
+        }
+    }
+
+    /// Fills the slice with clones of `value`.
+    ///
+    /// Analagous to [`slice::fill`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut slice = [1, 2, 3, 4, 5];
+    /// (&mut slice).step_by(2).fill(0);
+    /// assert_eq!(slice, [0, 2, 0, 4, 0]);
+    /// ```
+    fn fill(&mut self, value: Self::Output)
+    where
+        Self::Output: Clone,
+    {
+        if let Some(slice) = self.as_contiguous_mut() {
+            return <[Self::Output]>::fill(slice, value);
+        }
+
+        self.fill_with(|| value.clone());
+    }
+
+    /// Fills the slice with values returned by calling a closure repeatedly.
+    ///
+    /// Analagous to [`slice::fill_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut slice = [0; 5];
+    /// let mut n = 0;
+    /// (&mut slice).rev().fill_with(|| {
+    ///     n += 1;
+    ///     n
+    /// });
+    ///
+    /// assert_eq!(slice, [5, 4, 3, 2, 1]);
+    /// ```
+    fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> Self::Output,
+    {
+        if let Some(slice) = self.as_contiguous_mut() {
+            return <[Self::Output]>::fill_with(slice, f);
+        }
+
+        for i in 0..self.len() {
+            *self.get_mut(i).unwrap() = f();
+        }
+    }
+
+    /// Reverses the order of the elements in-place.
+    ///
+    /// Analagous to [`slice::reverse`]. See also [`Slice::rev`].
+    ///
+    /// To avoid aliasing, requires <code>Self: [Unique]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut a = [1, 2, 3];
+    /// let mut b = [4, 5];
+    ///
+    /// (&mut a).chain(&mut b).reverse();
+    /// assert_eq!(a, [5, 4, 3]);
+    /// assert_eq!(b, [2, 1]);
+    /// ```
+    fn reverse(&mut self)
+    where
+        Self: Unique,
+    {
+        if let Some(slice) = self.as_contiguous_mut() {
+            return <[Self::Output]>::reverse(slice);
+        }
+
+        let len = self.len();
+        for i in 0..len / 2 {
+            self.swap(i, len - 1 - i);
+        }
+    }
+
+    /// Rotates the elements in-place, such that the element at `k` becomes
+    /// the first. `k` may be larger than the length of the slice.
+    ///
+    /// Analagous to [`slice::rotate_left`]. See also [`Slice::rotated_left`],
+    /// which rotates lazily.
+    ///
+    /// To avoid aliasing, requires <code>Self: [Unique]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut a = [1, 2, 3];
+    /// let mut b = [4, 5];
+    ///
+    /// (&mut a).chain(&mut b).rotate_left(2);
+    /// assert_eq!(a, [3, 4, 5]);
+    /// assert_eq!(b, [1, 2]);
+    /// ```
+    fn rotate_left(&mut self, k: usize)
+    where
+        Self: Unique,
+    {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+
+        let k = k % len;
+        if let Some(slice) = self.as_contiguous_mut() {
+            return <[Self::Output]>::rotate_left(slice, k);
+        }
+
+        SliceOf::new(&mut *self, 0..k).unwrap().reverse();
+        SliceOf::new(&mut *self, k..len).unwrap().reverse();
+        self.reverse();
+    }
+
+    /// Rotates the elements in-place, such that the element at `len - k`
+    /// becomes the first. `k` may be larger than the length of the slice.
+    ///
+    /// Analagous to [`slice::rotate_right`]. See also
+    /// [`Slice::rotated_right`], which rotates lazily.
+    ///
+    /// To avoid aliasing, requires <code>Self: [Unique]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceMut;
+    /// let mut slice = [1, 2, 3, 4, 5];
+    /// slice.rotate_right(2);
+    /// assert_eq!(slice, [4, 5, 1, 2, 3]);
+    /// ```
+    fn rotate_right(&mut self, k: usize)
+    where
+        Self: Unique,
+    {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+
+        let k = k % len;
+        if let Some(slice) = self.as_contiguous_mut() {
+            return <[Self::Output]>::rotate_right(slice, k);
+        }
+
+        self.rotate_left(len - k);
+    }
+
+    /// Create a computed view of the slice that can be written through, using
+    /// a getter and a setter.
+    ///
+    /// Reading index `i` returns `get(&self[i])`, and
+    /// [`set`](Lens::set)`(i, value)` calls `put(&mut self[i], value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{SliceMut, SliceOwned};
+    /// let mut kelvin = [273.15, 373.15];
+    /// let mut celsius = (&mut kelvin).lens(|k| k - 273.15, |k, c| *k = c + 273.15);
+    ///
+    /// assert_eq!(celsius.get_owned(1), Some(100.0));
+    ///
+    /// celsius.set(0, -273.15);
+    /// assert_eq!(kelvin, [0.0, 373.15]);
+    /// ```
+    fn lens<G, P, U>(self, get: G, put: P) -> Lens<Self, G, P>
+    where
+        Self: Sized,
+        G: Fn(&Self::Output) -> U,
+        P: Fn(&mut Self::Output, U),
+    {
+        Lens(self, get, put)
+    }
+
+    /// Project each element to a place inside it, e.g. a field, which can
+    /// then be read and written through.
+    ///
+    /// Since [`Slice`] can only access elements through shared references,
+    /// and a `&mut` can't be made from one, this takes a shared projection `f`
+    /// as well as the mutable one, `f_mut`.
+    ///
+    /// # Panics
+    ///
+    /// `f` and `f_mut` must project to the same place, e.g. both to the same
+    /// field: reads go through `f` and writes through `f_mut`, so otherwise
+    /// mutators would compare one place but move another. Every mutable
+    /// access checks this, and panics if they disagree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceMut;
+    /// struct Record {
+    ///     name: &'static str,
+    ///     score: u32,
+    /// }
+    ///
+    /// let mut records = [
+    ///     Record { name: "a", score: 3 },
+    ///     Record { name: "b", score: 1 },
+    /// ];
+    ///
+    /// let mut scores = (&mut records).map_mut(|r| &r.score, |r| &mut r.score);
+    /// assert_eq!(scores, [3, 1]);
+    ///
+    /// scores[1] += 1;
+    /// scores.sort_unstable();
+    /// assert_eq!(records[0].score, 2);
+    /// assert_eq!(records[0].name, "a");
+    /// ```
+    fn map_mut<F, G, U>(self, f: F, f_mut: G) -> MapMut<Self, F, G>
+    where
+        Self: Sized,
+        F: Fn(&Self::Output) -> &U,
+        G: Fn(&mut Self::Output) -> &mut U,
+    {
+        MapMut(self, f, f_mut)
+    }
+
+    /// Reorders the slice such that the element at `index` is in its sorted
+    /// position, returning a reference to it.
+    ///
+    /// Every element before `index` will be less than or equal to it, and
+    /// every element after will be greater than or equal to it. Analagous to
+    /// [`slice::select_nth_unstable`].
+    ///
+    /// To avoid aliasing, requires <code>Self: [Unique]</code>.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out-of-bounds, panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut a = [5, 1, 4];
+    /// let mut b = [3, 2];
+    ///
+    /// let mut slice = (&mut a).chain(&mut b);
+    /// assert_eq!(*slice.select_nth_unstable(2), 3);
+    /// assert_eq!(slice[2], 3);
+    /// ```
+    fn select_nth_unstable(&mut self, index: usize) -> &mut Self::Output
+    where
+        Self: Unique,
+        Self::Output: Ord,
+    {
+        self.select_nth_unstable_by(index, Ord::cmp)
+    }
+
+    /// Reorders the slice with a comparator function such that the element
+    /// at `index` is in its sorted position, returning a reference to it.
+    ///
+    /// See [`SliceMut::select_nth_unstable`].
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out-of-bounds, panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut slice = [5, 1, 4, 3, 2];
+    /// let mut rev = (&mut slice).rev();
+    /// let second = rev.select_nth_unstable_by(1, |a, b| b.cmp(a));
+    /// assert_eq!(*second, 4);
+    /// ```
+    fn select_nth_unstable_by<F>(&mut self, index: usize, mut compare: F) -> &mut Self::Output
+    where
+        Self: Unique,
+        F: FnMut(&Self::Output, &Self::Output) -> Ordering,
+    {
+        // checked separately, since returning the borrow from inside the
+        // `if let` would keep `self` borrowed for the rest of the function
+        if self.as_contiguous_mut().is_some() {
+            let slice = self.as_contiguous_mut().unwrap();
+            return <[Self::Output]>::select_nth_unstable_by(slice, index, compare).1;
+        }
+
+        let len = self.len();
+        if index >= len {
+            panic!("index out of bounds: the len is {len} but the index is {index}");
+        }
+
+        sort::select(self, index, &mut compare);
+        self.get_mut(index).unwrap()
+    }
+
+    /// Sorts the slice in-place, preserving the order of equal elements. Only
+    /// available on feature `alloc`.
+    ///
+    /// Allocates a list of indices, leaving the elements themselves where they
+    /// are until they're moved into place; contiguous slices use
+    /// [`slice::sort`]. To sort without allocating, see
+    /// [`SliceMut::sort_by_with_buffer`]. Analagous to [`slice::sort`].
+    ///
+    /// To avoid aliasing, requires <code>Self: [Unique]</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut a = [5, 1, 4];
+    /// let mut b = vec![3, 2];
+    ///
+    /// (&mut a).chain(&mut b).sort();
+    /// assert_eq!(a, [1, 2, 3]);
+    /// assert_eq!(b, [4, 5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sort(&mut self)
+    where
+        Self: Unique,
+        Self::Output: Ord,
+    {
+        self.sort_by(Ord::cmp);
+    }
+
+    /// Sorts the slice in-place with a comparator function, preserving the
+    /// order of equal elements. Only available on feature `alloc`.
+    ///
+    /// See [`SliceMut::sort`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut slice = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+    /// (&mut slice).rev().sort_by(|a, b| a.0.cmp(&b.0));
+    /// assert_eq!(slice, [(1, 'a'), (1, 'c'), (0, 'b'), (0, 'd')]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sort_by<F>(&mut self, mut compare: F)
+    where
+        Self: Unique,
+        F: FnMut(&Self::Output, &Self::Output) -> Ordering,
+    {
+        if let Some(slice) = self.as_contiguous_mut() {
+            return <[Self::Output]>::sort_by(slice, compare);
+        }
+
+        sort::index_sort(self, &mut compare);
+    }
+
+    /// Sorts the slice in-place with a key extraction function, preserving
+    /// the order of equal elements. Only available on feature `alloc`.
     ///
-    /// Analagous to [`Iterator::map`].
+    /// See [`SliceMut::sort`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use slice_utils::SliceBorrowed;
-    /// let slice = [0, 1, 2].map(|x| x != 0);
-    /// assert_eq!(slice, [false, true, true]);
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut slice = [-5_i32, 1, 5, 3, -1];
+    /// (&mut slice).slice(1..).unwrap().sort_by_key(|x| x.abs());
+    /// assert_eq!(slice, [-5, 1, -1, 3, 5]);
     /// ```
-    fn map<F: Fn(&Self::Output) -> R, R>(self, f: F) -> MapBorrowed<Self, F>
+    #[cfg(feature = "alloc")]
+    fn sort_by_key<K, F>(&mut self, mut f: F)
     where
-        Self: Sized,
+        Self: Unique,
+        K: Ord,
+        F: FnMut(&Self::Output) -> K,
     {
-        MapBorrowed(self, f)
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
     }
 
-    /// Create a new slice that clones each value on access.
-    /// Analagous to <code>self.[map](SliceBorrowed::map)([Clone::clone])</code>.
+    /// Sorts the slice in-place with a comparator function, preserving the
+    /// order of equal elements, without allocating.
+    ///
+    /// Uses a merge sort that swaps elements in and out of `scratch`, which
+    /// must be at least half as long as `self`. Its contents are only used as
+    /// placeholders: afterwards, it holds the same elements, in an unspecified
+    /// order.
+    ///
+    /// To avoid aliasing, requires <code>Self: [Unique]</code>.
+    ///
+    /// # Panics
+    ///
+    /// If `scratch.len() < self.len() / 2`, panics.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use slice_utils::SliceBorrowed;
-    /// # #[derive(Debug, PartialEq)]
-    /// struct Foo(u32);
-    /// impl Clone for Foo {
-    ///     fn clone(&self) -> Foo { Foo(self.0 + 1) }
-    /// }
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut a = [(1, 'a'), (0, 'b'), (1, 'c')];
+    /// let mut b = [(0, 'd'), (2, 'e')];
+    /// let mut scratch = [(0, ' '); 2];
     ///
-    /// let slice = [Foo(1), Foo(2)];
-
-    /// assert_eq!(slice.cloned(), [Foo(2), Foo(3)]);
+    /// (&mut a)
+    ///     .chain(&mut b)
+    ///     .sort_by_with_buffer(&mut scratch, |x, y| x.0.cmp(&y.0));
+    ///
+    /// assert_eq!(a, [(0, 'b'), (0, 'd'), (1, 'a')]);
+    /// assert_eq!(b, [(1, 'c'), (2, 'e')]);
     /// ```
-    fn cloned(self) -> Cloned<Self>
+    fn sort_by_with_buffer<B, F>(&mut self, scratch: &mut B, mut compare: F)
     where
-        Self: Sized,
-        Self::Output: Clone,
+        Self: Unique,
+        B: SliceMut<Output = Self::Output> + ?Sized,
+        F: FnMut(&Self::Output, &Self::Output) -> Ordering,
     {
-        MapBorrowed(self, Clone::clone)
+        sort::merge_sort(self, scratch, &mut compare);
     }
 
-    /// Creates an iterator over the slice.
+    /// Sorts the slice in-place, without preserving the order of equal
+    /// elements.
+    ///
+    /// Works through [`SliceMut::get_mut`], so non-contiguous slices can be
+    /// sorted without allocating; contiguous slices use
+    /// [`slice::sort_unstable`]. Analagous to [`slice::sort_unstable`].
+    ///
+    /// To avoid aliasing, requires <code>Self: [Unique]</code>.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use slice_utils::{Slice, SliceBorrowed};
-    /// let slice = [1, 2].chain([3]);
-    /// let mut iter = slice.iter();
-    /// assert_eq!(iter.next(), Some(&1));
-    /// assert_eq!(iter.next(), Some(&2));
-    /// assert_eq!(iter.next(), Some(&3));
-    /// assert!(iter.next().is_none());
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut a = [5, 1, 4];
+    /// let mut b = [3, 2];
+    ///
+    /// (&mut a).chain(&mut b).sort_unstable();
+    /// assert_eq!(a, [1, 2, 3]);
+    /// assert_eq!(b, [4, 5]);
     /// ```
-    fn iter(&self) -> IterBorrowed<Self> {
-        IterBorrowed::new(self)
+    fn sort_unstable(&mut self)
+    where
+        Self: Unique,
+        Self::Output: Ord,
+    {
+        self.sort_unstable_by(Ord::cmp);
     }
 
-    /// Return a slice/iterator over slices covering overlapping portions of the
-    /// slice.
-    ///
-    /// Analagous to [`slice::windows`].
+    /// Sorts the slice in-place with a comparator function, without
+    /// preserving the order of equal elements.
     ///
-    /// # Panics
-    ///
-    /// If `size == 0`, panics.
+    /// See [`SliceMut::sort_unstable`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use slice_utils::SliceBorrowed;
-    /// let slice = [1, 2, 3, 4, 5];
-    /// let mut windows = slice.windows(3);
-    ///
-    /// assert_eq!(windows.next().unwrap(), [1, 2, 3]);
-    /// assert_eq!(windows.next().unwrap(), [2, 3, 4]);
-    /// assert_eq!(windows.next().unwrap(), [3, 4, 5]);
-    /// assert!(windows.next().is_none());
-    /// # {
-    /// # use slice_utils::SliceOwned;
-    /// assert_eq!(windows.get_owned(1).unwrap(), [2, 3, 4]);
-    /// # }
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut slice = [5, 1, 4, 3, 2];
+    /// (&mut slice).rev().sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(slice, [1, 2, 3, 4, 5]);
     /// ```
-    fn windows(&self, size: usize) -> WindowsBorrowed<Self> {
-        WindowsBorrowed::new(self, size)
-    }
-}
+    fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        Self: Unique,
+        F: FnMut(&Self::Output, &Self::Output) -> Ordering,
+    {
+        if let Some(slice) = self.as_contiguous_mut() {
+            return <[Self::Output]>::sort_unstable_by(slice, compare);
+        }
 
-/// A [`Slice`] that can return mutably borrowed values.
-pub trait SliceMut: Slice {
-    /// Index the slice, returning a mutably borrowed value.
-    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output>;
+        let len = self.len();
+        sort::heapsort(self, 0, len, &mut compare);
+    }
 
-    /// Returns `(&mut self[..at], &mut self[at..])`.
-    /// Returns `None` if `at` is out-of-bounds.
-    ///
-    /// Analagous to [`slice::split_mut`].
+    /// Sorts the slice in-place with a key extraction function, without
+    /// preserving the order of equal elements.
     ///
-    /// To avoid aliasing, requires <code>Self: [Unique]</code>.
+    /// See [`SliceMut::sort_unstable`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use slice_utils::SliceMut;
-    /// let mut slice = [1, 2, 3, 4, 5];
-    /// let (mut left, mut right) = SliceMut::split_mut(&mut slice, 2).unwrap();
-    ///
-    /// assert_eq!(left, [1, 2, 3]);
-    /// assert_eq!(right, [4, 5]);
-    ///
-    /// left[0] = 0;
-    /// right[0] = 0;
-    /// assert_eq!(slice, [0, 2, 3, 0, 5]);
+    /// # use slice_utils::{Slice, SliceMut};
+    /// let mut slice = [-5_i32, 1, -4, 3, 2];
+    /// (&mut slice).step_by(2).sort_unstable_by_key(|x| x.abs());
+    /// assert_eq!(slice, [2, 1, -4, 3, -5]);
     /// ```
-    fn split_mut(&mut self, at: usize) -> Option<(SplitMut<Self>, SplitMut<Self>)>
+    fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
     where
         Self: Unique,
+        K: Ord,
+        F: FnMut(&Self::Output) -> K,
     {
-        SplitMut::new(self, at)
+        self.sort_unstable_by(|a, b| f(a).cmp(&f(b)));
     }
 
-    /// Copy all the items from `src` into `self`.
+    /// Swaps two elements of the slice.
     ///
-    /// Similar to [`slice::clone_from_slice`].
+    /// Analagous to [`slice::swap`].
+    ///
+    /// To avoid aliasing, requires <code>Self: [Unique]</code>.
     ///
     /// # Panics
     ///
-    /// The lengths must match. If you only want to copy a sub-slice, you can
-    /// slice each side down to the desired range.
+    /// If either index is out-of-bounds, panics.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use slice_utils::{Slice, SliceMut};
-    /// let mut x = [1, 2, 3, 4, 5];
-    /// let y = [6, 7];
+    /// let mut a = [1, 2];
+    /// let mut b = [3, 4];
     ///
-    /// (&mut x).slice(3..).unwrap().copy_from_slice(&y);
-    /// 
-    /// assert_eq!(x, [1, 2, 3, 6, 7]);
+    /// (&mut a).chain(&mut b).swap(0, 3);
+    /// assert_eq!(a, [4, 2]);
+    /// assert_eq!(b, [3, 1]);
     /// ```
-    fn copy_from_slice<S>(&mut self, src: &S)
+    fn swap(&mut self, a: usize, b: usize)
     where
-        S: Slice<Output = Self::Output>,
-        S::Output: Clone,
+        Self: Unique,
     {
-        if self.len() != src.len() {
-            panic!("source slice length ({}) does not match destination slice length ({})",
-                src.len(), self.len())
+        if let Some(slice) = self.as_contiguous_mut() {
+            return <[Self::Output]>::swap(slice, a, b);
         }
 
-        for i in 0..src.len() {
-            src.get_with(i, &mut |item| *self.get_mut(i).unwrap_or_else(|| {
-                panic!("destination slice is shorter than source slice");
-            }) = item.clone());
+        let len = self.len();
+        let oob = |i| -> ! { panic!("index out of bounds: the len is {len} but the index is {i}") };
+
+        if a == b {
+            if a >= len {
+                oob(a);
+            }
+            return;
+        }
+
+        // Aborts if dropped, i.e. if `get_mut` panics while an item is
+        // duplicated, instead of letting it be dropped twice
+        struct Guard;
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                panic!("`get_mut` panicked in the middle of a swap");
+            }
         }
+
+        // Each `get_mut` borrows the whole slice, which invalidates any earlier
+        // reference into it, so the items are moved one at a time instead of
+        // swapped through two live pointers.
+        //
+        // SAFETY: `Unique` guarantees that `a` and `b` are different items.
+        // `x` is a bitwise copy that's only used once `a` is overwritten; until
+        // then it's never dropped, so a panic in the second `get_mut` leaves the
+        // slice untouched.
+        unsafe {
+            let x = ManuallyDrop::new(ptr::read(self.get_mut(a).unwrap_or_else(|| oob(a))));
+            let y = ptr::replace(
+                self.get_mut(b).unwrap_or_else(|| oob(b)),
+                ManuallyDrop::into_inner(x),
+            );
+
+            let guard = Guard;
+            ptr::write(self.get_mut(a).unwrap_or_else(|| oob(a)), y);
+            mem::forget(guard);
+        }
+    }
+
+    /// Zip two mutable slices together, so they can be updated in lockstep.
+    ///
+    /// See [`ZipMut`] for how to access the items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceMut;
+    /// let mut xs = [1, 2, 3];
+    /// let mut ys = [4, 5, 6];
+    /// let mut zipped = (&mut xs).zip_mut(&mut ys);
+    ///
+    /// let (x, y) = zipped.get_mut_pair(0).unwrap();
+    /// *x += *y;
+    /// *y = 0;
+    ///
+    /// assert_eq!(xs, [5, 2, 3]);
+    /// assert_eq!(ys, [0, 5, 6]);
+    /// ```
+    fn zip_mut<O: SliceMut>(self, other: O) -> ZipMut<Self, O>
+    where
+        Self: Sized,
+    {
+        ZipMut(self, other)
     }
 }
 
@@ -521,10 +2040,28 @@ pub trait SliceOwned: Slice {
     /// assert_eq!(iter.next().unwrap(), [5]);
     /// assert!(iter.next().is_none());
     /// ```
-    fn chunks(&self, size: usize) -> ChunksOwned<Self> {
+    fn chunks(&self, size: usize) -> ChunksOwned<'_, Self> {
         ChunksOwned::new(self, size)
     }
 
+    /// Creates a slice returning each item along with its index.
+    ///
+    /// Analagous to [`Iterator::enumerate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceOwned;
+    /// let slice = ['a', 'b', 'c'].enumerate();
+    /// assert_eq!(slice, [(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// ```
+    fn enumerate(self) -> EnumerateOwned<Self>
+    where
+        Self: Sized,
+    {
+        EnumerateOwned(self)
+    }
+
     /// Call a closure on index, returning a new type.
     ///
     /// Analagous to [`Iterator::map`].
@@ -543,6 +2080,24 @@ pub trait SliceOwned: Slice {
         MapOwned(self, f)
     }
 
+    /// Call a closure on each index and item, returning a new type.
+    ///
+    /// Like [`SliceOwned::map`], but the closure is also given the index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceOwned;
+    /// let slice = [3, 2, 1].map_with_index(|i, x| i * x);
+    /// assert_eq!(slice, [0, 2, 2]);
+    /// ```
+    fn map_with_index<F: Fn(usize, Self::Output) -> R, R>(self, f: F) -> MapWithIndexOwned<Self, F>
+    where
+        Self: Sized,
+    {
+        MapWithIndexOwned(self, f)
+    }
+
     /// Creates an iterator over the slice.
     ///
     /// # Examples
@@ -563,6 +2118,68 @@ pub trait SliceOwned: Slice {
         IterOwned::new(self)
     }
 
+    /// Creates a parallel iterator over the slice, which splits it by index.
+    /// Only available on feature `rayon`.
+    ///
+    /// Takes `self` by value, so it's named apart from rayon's `par_iter`,
+    /// which borrows; otherwise e.g. `vec.par_iter()` would be ambiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::{Slice, SliceOwned};
+    /// use rayon::prelude::*;
+    ///
+    /// let slice = SliceOwned::map([1, 2].chain([3]), |x| x * 10);
+    /// let collected: Vec<_> = slice.par_iter_owned().enumerate().collect();
+    /// assert_eq!(collected, [(0, 10), (1, 20), (2, 30)]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn par_iter_owned(self) -> ParIterOwned<Self>
+    where
+        Self: Sized + Send + Sync,
+        Self::Output: Send,
+    {
+        ParIterOwned(self)
+    }
+
+    /// Creates the cartesian product of two slices, where indexing returns a
+    /// tuple of their items.
+    ///
+    /// The right-hand slice varies fastest, so index `i` returns
+    /// `(self[i / other.len()], other[i % other.len()])`.
+    ///
+    /// The length saturates at `usize::MAX`, so e.g. the product with a
+    /// [`cycle`](Slice::cycle) doesn't overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceOwned;
+    /// let a = [1, 2];
+    /// let b = ['a', 'b', 'c'];
+    /// let slice = a.product(b);
+    ///
+    /// assert_eq!(
+    ///     slice,
+    ///     [
+    ///         (1, 'a'),
+    ///         (1, 'b'),
+    ///         (1, 'c'),
+    ///         (2, 'a'),
+    ///         (2, 'b'),
+    ///         (2, 'c'),
+    ///     ]
+    /// );
+    /// assert_eq!(slice.get_owned(4), Some((2, 'b')));
+    /// ```
+    fn product<O: SliceOwned>(self, other: O) -> Product<Self, O>
+    where
+        Self: Sized,
+    {
+        Product(self, other)
+    }
+
     /// Try to collect the slice into an array, failing if the lengths don't
     /// match up.
     ///
@@ -604,7 +2221,7 @@ pub trait SliceOwned: Slice {
     /// assert!(windows.next().is_none());
     /// assert_eq!(windows.get_owned(1).unwrap(), [2, 3, 4]);
     /// ```
-    fn windows(&self, size: usize) -> WindowsOwned<Self> {
+    fn windows(&self, size: usize) -> WindowsOwned<'_, Self> {
         WindowsOwned::new(self, size)
     }
 
@@ -637,10 +2254,10 @@ pub trait SliceOwned: Slice {
         Zip(self, other)
     }
 
-    /// Collect the slice into a `Vec`. Only available on feature `std`.
+    /// Collect the slice into a `Vec`. Only available on feature `alloc`.
     ///
     /// Analagous to [`Iterator::collect`].
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn collect(&self) -> Vec<Self::Output> {
         let mut v = Vec::with_capacity(self.len());
         for i in 0..self.len() {
@@ -709,6 +2326,97 @@ pub trait ContiguousMut: SliceMut + Unique {
 /// must never return aliasing references.
 pub unsafe trait Unique {}
 
+/// A marker trait confirming that [`ContiguousBorrowed::contiguous`] always
+/// returns the same items, as long as the slice isn't mutated.
+///
+/// [`Permute`] relies on this to stay [`Unique`]: its indices are only checked
+/// once, on creation.
+///
+/// # Safety
+///
+/// Two calls to `contiguous` with no mutable access to `self` in between must
+/// return slices with the same length and the same items.
+pub unsafe trait StableContiguous: ContiguousBorrowed {}
+
+/// A [`SliceMut`] whose items can be reached through a [`RawSlice`] handle,
+/// without borrowing the whole slice.
+///
+/// This is what lets [`SplitMut`] and [`Lane`] hand out references to their
+/// own items while other views of the same slice hold references to theirs:
+/// going through [`SliceMut::get_mut`] would need a `&mut` to the whole
+/// slice, which invalidates every other reference into it. Instead, a handle
+/// is captured once, when the slice is split, and every access goes through
+/// it.
+///
+/// Adapters implement this whenever the slices they wrap do, and their handle
+/// is usually the same adapter wrapped around the inner handles, e.g.
+/// `Chain<S1::Raw, S2::Raw>`.
+///
+/// # Safety
+///
+/// `raw_mut` must not create any mutable reference, nor any reference to an
+/// item of the slice; shared references to the slice's own bookkeeping (e.g.
+/// the indices of a [`Permute`]) are allowed, since it may be called from
+/// several threads at once. The handle must uphold the contract of
+/// [`RawSlice`] for this slice.
+pub unsafe trait SliceRawMut: SliceMut + Unique {
+    /// A handle to the items of the slice.
+    type Raw: RawSlice<Output = Self::Output>;
+
+    /// Captures a handle to the items of the slice behind `this`.
+    ///
+    /// # Safety
+    ///
+    /// `this` must be valid for reads.
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw;
+}
+
+/// A handle to the items of a [`SliceRawMut`], which hands out raw pointers
+/// to them; see [`SliceRawMut::raw_mut`].
+///
+/// A handle is plain data, so copying it or using it never borrows the slice
+/// it was captured from.
+///
+/// # Safety
+///
+/// `len` and `get_mut` must agree with [`Slice::len`] and
+/// [`SliceMut::get_mut`] on the slice the handle was captured from, and
+/// distinct indices must give distinct pointers. `get_mut` must not create any
+/// reference to an item, not even the one at `index`, nor any mutable
+/// reference to anything else: the caller may already hold references to that
+/// item, or to another part of it, possibly on another thread.
+pub unsafe trait RawSlice: Copy {
+    /// The type of the items.
+    type Output;
+
+    /// Returns the length of the slice.
+    ///
+    /// # Safety
+    ///
+    /// The slice the handle was captured from must still be valid, and must
+    /// not have been moved or accessed since, other than through handles to
+    /// it.
+    unsafe fn len(&self) -> usize;
+
+    /// Returns `true` if the slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// See [`RawSlice::len`].
+    unsafe fn is_empty(&self) -> bool {
+        // SAFETY: guaranteed by the caller
+        unsafe { self.len() == 0 }
+    }
+
+    /// Returns a pointer to the item at `index`, or `None` if it's
+    /// out-of-bounds.
+    ///
+    /// # Safety
+    ///
+    /// See [`RawSlice::len`].
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output>;
+}
+
 /// A slice made by calling a closure on the index.
 ///
 /// Analagous to [`core::iter::from_fn`].
@@ -726,3 +2434,84 @@ where
 {
     FromFn::new(f, len)
 }
+
+/// Interleaves any number of slices round-robin, e.g.
+/// [A, B, C, A, B, C, ...].
+///
+/// The slices are given as an array or `Vec`, and the result ends as soon as
+/// the slice whose turn it is runs out.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slice_utils::SliceOwned;
+/// let left = [1, 2, 3];
+/// let center = [4, 5];
+/// let right = [6, 7];
+/// let slice = slice_utils::interleave_n([&left[..], &center, &right]);
+///
+/// assert_eq!(slice, [1, 4, 6, 2, 5, 7, 3]);
+/// ```
+pub fn interleave_n<C>(slices: C) -> InterleaveN<C>
+where
+    C: ContiguousBorrowed,
+    C::Output: Slice,
+{
+    InterleaveN::new(slices)
+}
+
+/// Splits the slice into chunks of `size` items (the last may be shorter), and
+/// calls `f` on each of them in parallel.
+///
+/// At most [`std::thread::available_parallelism`] threads are spawned, each
+/// handling a contiguous run of chunks in order.
+///
+/// Works on any <code>[SliceMut] + [Unique]</code> that implements
+/// [`SliceRawMut`], not only contiguous ones. Returns once every chunk has been
+/// processed; if `f` panics, the panic is propagated.
+///
+/// # Panics
+///
+/// If `size == 0`, panics.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slice_utils::{Slice, SliceMut};
+/// let mut a = [1, 2, 3];
+/// let mut b = [4, 5, 6, 7];
+/// let mut slice = (&mut a).chain(&mut b);
+///
+/// slice_utils::scoped_chunks_mut(&mut slice, 2, |mut chunk| {
+///     chunk.reverse();
+/// });
+///
+/// assert_eq!(a, [2, 1, 4]);
+/// assert_eq!(b, [3, 6, 5, 7]);
+/// ```
+#[cfg(feature = "std")]
+pub fn scoped_chunks_mut<S, F>(slice: &mut S, size: usize, f: F)
+where
+    S: SliceRawMut + Sync + ?Sized,
+    S::Output: Send,
+    F: Fn(SplitMut<'_, S>) + Sync,
+{
+    assert!(size != 0, "cannot call `scoped_chunks_mut` with size = 0");
+
+    let count = slice.len().div_ceil(size);
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let workers = threads.min(count);
+    if workers == 0 {
+        return;
+    }
+    let per_worker = count.div_ceil(workers);
+
+    let f = &f;
+    let mut chunks = SplitMut::chunks(slice, size);
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            let run: Vec<_> = chunks.by_ref().take(per_worker).collect();
+            scope.spawn(move || run.into_iter().for_each(f));
+        }
+    });
+}
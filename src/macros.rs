@@ -0,0 +1,55 @@
+/// Zips up to four slices together, where indexing returns a tuple of their
+/// items.
+///
+/// Expands to [`Zip`](crate::Zip), [`Zip3`](crate::Zip3) or
+/// [`Zip4`](crate::Zip4), so the tuples stay flat instead of nesting like
+/// `((a, b), c)`. A single slice is returned as-is.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slice_utils::SliceOwned;
+/// let slice = slice_utils::zip!([1, 2, 3], ['a', 'b'], [true, false, true]);
+/// assert_eq!(slice, [(1, 'a', true), (2, 'b', false)]);
+/// ```
+#[macro_export]
+macro_rules! zip {
+    ($a:expr $(,)?) => {
+        $a
+    };
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::Zip($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)?) => {
+        $crate::Zip3($a, $b, $c)
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {
+        $crate::Zip4($a, $b, $c, $d)
+    };
+    ($($rest:expr),+ $(,)?) => {
+        compile_error!("`zip!` supports at most four slices")
+    };
+}
+
+/// Chains any number of slices together.
+///
+/// The chains are nested to the right, as `Chain(a, Chain(b, c))`, so an index
+/// only has to be compared against the length of each slice before it,
+/// rather than the length of every chain before it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use slice_utils::SliceOwned;
+/// let slice = slice_utils::chain!([1, 2], [3], [4, 5, 6], [7]);
+/// assert_eq!(slice, [1, 2, 3, 4, 5, 6, 7]);
+/// ```
+#[macro_export]
+macro_rules! chain {
+    ($a:expr $(,)?) => {
+        $a
+    };
+    ($a:expr, $($rest:expr),+ $(,)?) => {
+        $crate::Chain($a, $crate::chain!($($rest),+))
+    };
+}
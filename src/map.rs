@@ -1,4 +1,6 @@
-use crate::{Slice, SliceBorrowed, SliceOwned};
+use core::ptr;
+
+use crate::{Slice, SliceBorrowed, SliceMut, SliceOwned, Unique};
 
 macro_rules! map {
     ($owned:ident, $in:ty, $fn:ident) => {
@@ -38,4 +40,143 @@ macro_rules! map {
 
 map!(Owned, S::Output, get_owned);
 map!(Borrowed, &S::Output, get);
-// map!(MapMut, &mut S::Output, SliceMut, get_mut);
+
+macro_rules! map_with_index {
+    ($owned:ident, $in:ty, $fn:ident) => {
+        paste::paste! {
+            #[doc = "Maps using a closure on index and item; see [`" [<Slice $owned>] "::map_with_index`]."]
+            #[derive(Clone, Copy, Hash)]
+            pub struct [<MapWithIndex $owned>]<S, F>(pub S, pub F);
+
+            impl<S, F, U> Slice for [<MapWithIndex $owned>]<S, F>
+            where
+                S: [<Slice $owned>],
+                F: Fn(usize, $in) -> U,
+            {
+                type Output = U;
+
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+
+                fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+                    Some(f(&self.get_owned(index)?))
+                }
+            }
+
+            impl<S, F, U> SliceOwned for [<MapWithIndex $owned>]<S, F>
+            where
+                S: [<Slice $owned>],
+                F: Fn(usize, $in) -> U,
+            {
+                fn get_owned(&self, index: usize) -> Option<U> {
+                    Some((self.1)(index, self.0.$fn(index)?))
+                }
+            }
+        }
+    };
+}
+
+map_with_index!(Owned, S::Output, get_owned);
+map_with_index!(Borrowed, &S::Output, get);
+
+/// Projects each element to a place inside it; see [`SliceBorrowed::map_ref`].
+#[derive(Clone, Copy, Hash)]
+pub struct MapRef<S, F>(pub S, pub F);
+
+impl<S, F, U> Slice for MapRef<S, F>
+where
+    S: Slice,
+    F: Fn(&S::Output) -> &U,
+{
+    type Output = U;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        self.0.get_with(index, &mut |x| f((self.1)(x)))
+    }
+}
+
+impl<S, F, U> SliceBorrowed for MapRef<S, F>
+where
+    S: SliceBorrowed,
+    F: Fn(&S::Output) -> &U,
+{
+    fn get(&self, index: usize) -> Option<&U> {
+        self.0.get(index).map(&self.1)
+    }
+}
+
+/// Projects each element to a mutable place inside it; see
+/// [`SliceMut::map_mut`].
+///
+/// Since [`Slice`] can only access elements through shared references, this
+/// holds a shared projection alongside the mutable one.
+///
+/// This isn't [`SliceRawMut`](crate::SliceRawMut): the projection needs a
+/// `&mut` to the whole element, which would invalidate references to it held
+/// elsewhere, so it can't be split or reached through a [`RawSlice`].
+///
+/// ```rust,compile_fail
+/// # use slice_utils::SliceMut;
+/// let mut pairs = [(1, 2), (3, 4)];
+/// let mut seconds = (&mut pairs).map_mut(|p| &p.1, |p| &mut p.1);
+/// let _ = seconds.split_mut(1);
+/// ```
+///
+/// [`RawSlice`]: crate::RawSlice
+#[derive(Clone, Copy, Hash)]
+pub struct MapMut<S, F, G>(pub S, pub F, pub G);
+
+impl<S, F, G, U> Slice for MapMut<S, F, G>
+where
+    S: Slice,
+    F: Fn(&S::Output) -> &U,
+    G: Fn(&mut S::Output) -> &mut U,
+{
+    type Output = U;
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        self.0.get_with(index, &mut |x| f((self.1)(x)))
+    }
+}
+
+impl<S, F, G, U> SliceBorrowed for MapMut<S, F, G>
+where
+    S: SliceBorrowed,
+    F: Fn(&S::Output) -> &U,
+    G: Fn(&mut S::Output) -> &mut U,
+{
+    fn get(&self, index: usize) -> Option<&U> {
+        self.0.get(index).map(&self.1)
+    }
+}
+
+impl<S, F, G, U> SliceMut for MapMut<S, F, G>
+where
+    S: SliceMut,
+    F: Fn(&S::Output) -> &U,
+    G: Fn(&mut S::Output) -> &mut U,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut U> {
+        let item = self.0.get_mut(index)?;
+        let place: *const U = (self.1)(item);
+        let place_mut = (self.2)(item);
+        if !ptr::eq(place, place_mut) {
+            panic!("`map_mut` projections disagree at index {index}");
+        }
+
+        Some(place_mut)
+    }
+}
+
+// SAFETY: the underlying slice is `Unique`, and the projection can only return
+// places borrowed from the element it was given
+unsafe impl<S, F, G> Unique for MapMut<S, F, G> where S: Unique {}
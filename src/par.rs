@@ -0,0 +1,246 @@
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+use crate::{IterBorrowed, IterOwned, Slice, SliceBorrowed, SliceOwned, SliceRawMut, SplitMut};
+
+/// A parallel iterator over a slice; see [`SliceOwned::par_iter_owned`].
+#[derive(Debug, Clone, Copy)]
+pub struct ParIterOwned<S>(pub S);
+
+impl<S> ParallelIterator for ParIterOwned<S>
+where
+    S: SliceOwned + Send + Sync,
+    S::Output: Send,
+{
+    type Item = S::Output;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+impl<S> IndexedParallelIterator for ParIterOwned<S>
+where
+    S: SliceOwned + Send + Sync,
+    S::Output: Send,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterOwned::new(&self.0))
+    }
+}
+
+impl<S> Producer for IterOwned<S>
+where
+    S: SliceOwned + Clone + Send,
+    S::Output: Send,
+{
+    type Item = S::Output;
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        IterOwned::split_at(self, index)
+    }
+}
+
+/// A parallel iterator over a slice; see [`SliceBorrowed::par_iter_borrowed`].
+#[derive(Debug, Clone, Copy)]
+pub struct ParIterBorrowed<'a, S: ?Sized>(pub &'a S);
+
+impl<'a, S> ParallelIterator for ParIterBorrowed<'a, S>
+where
+    S: SliceBorrowed + Sync + ?Sized,
+    S::Output: Sync,
+{
+    type Item = &'a S::Output;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+impl<S> IndexedParallelIterator for ParIterBorrowed<'_, S>
+where
+    S: SliceBorrowed + Sync + ?Sized,
+    S::Output: Sync,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(IterBorrowed::new(self.0))
+    }
+}
+
+impl<'a, S> Producer for IterBorrowed<'a, S>
+where
+    S: SliceBorrowed + Sync + ?Sized,
+    S::Output: Sync,
+{
+    type Item = &'a S::Output;
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        IterBorrowed::split_at(self, index)
+    }
+}
+
+/// A parallel iterator over disjoint mutable chunks of a slice; see
+/// [`SliceMut::par_split_chunks_mut`](crate::SliceMut::par_split_chunks_mut).
+pub struct ParChunksMut<'a, S: SliceRawMut + ?Sized> {
+    data: SplitMut<'a, S>,
+    size: usize,
+}
+
+impl<'a, S> ParChunksMut<'a, S>
+where
+    S: SliceRawMut + ?Sized,
+{
+    /// Creates a parallel iterator over chunks of the slice; see
+    /// [`SliceMut::par_split_chunks_mut`](crate::SliceMut::par_split_chunks_mut).
+    ///
+    /// # Panics
+    ///
+    /// If `size == 0`, panics.
+    pub fn new(data: &'a mut S, size: usize) -> Self {
+        assert!(
+            size != 0,
+            "cannot call `par_split_chunks_mut` with size = 0"
+        );
+
+        let len = data.len();
+        let [data] = SplitMut::new_many(data, [len]).unwrap();
+        Self { data, size }
+    }
+}
+
+impl<'a, S> ParallelIterator for ParChunksMut<'a, S>
+where
+    S: SliceRawMut + Sync + ?Sized,
+    S::Output: Send,
+{
+    type Item = SplitMut<'a, S>;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(IndexedParallelIterator::len(self))
+    }
+}
+
+impl<S> IndexedParallelIterator for ParChunksMut<'_, S>
+where
+    S: SliceRawMut + Sync + ?Sized,
+    S::Output: Send,
+{
+    fn len(&self) -> usize {
+        self.data.len().div_ceil(self.size)
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(ChunksMut {
+            data: self.data,
+            size: self.size,
+        })
+    }
+}
+
+/// Serves as both the producer and the sequential iterator for
+/// [`ParChunksMut`].
+struct ChunksMut<'a, S: SliceRawMut + ?Sized> {
+    data: SplitMut<'a, S>,
+    size: usize,
+}
+
+impl<'a, S> Iterator for ChunksMut<'a, S>
+where
+    S: SliceRawMut + ?Sized,
+{
+    type Item = SplitMut<'a, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            None
+        } else {
+            Some(self.data.take_front(self.size))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.data.len().div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<S> DoubleEndedIterator for ChunksMut<'_, S>
+where
+    S: SliceRawMut + ?Sized,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.data.len() % self.size {
+            _ if self.data.is_empty() => None,
+            0 => Some(self.data.take_back(self.size)),
+            rem => Some(self.data.take_back(rem)),
+        }
+    }
+}
+
+impl<S> ExactSizeIterator for ChunksMut<'_, S> where S: SliceRawMut + ?Sized {}
+
+impl<'a, S> Producer for ChunksMut<'a, S>
+where
+    S: SliceRawMut + Sync + ?Sized,
+    S::Output: Send,
+{
+    type Item = SplitMut<'a, S>;
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let front = self.data.take_front(index * self.size);
+        (
+            Self {
+                data: front,
+                size: self.size,
+            },
+            self,
+        )
+    }
+}
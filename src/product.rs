@@ -0,0 +1,40 @@
+use crate::{Slice, SliceOwned};
+
+/// The cartesian product of two slices; see [`SliceOwned::product`].
+#[derive(Clone, Copy, Hash)]
+pub struct Product<S1, S2>(pub S1, pub S2);
+
+impl<S1, S2> Slice for Product<S1, S2>
+where
+    S1: SliceOwned,
+    S2: SliceOwned,
+{
+    type Output = (S1::Output, S2::Output);
+
+    fn len(&self) -> usize {
+        // saturate like `Cycle`, so infinite operands stay "infinite"
+        self.0.len().saturating_mul(self.1.len())
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        Some(f(&self.get_owned(index)?))
+    }
+}
+
+impl<S1, S2> SliceOwned for Product<S1, S2>
+where
+    S1: SliceOwned,
+    S2: SliceOwned,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        let width = self.1.len();
+        if width == 0 || index >= self.len() {
+            None
+        } else {
+            Some((
+                self.0.get_owned(index / width)?,
+                self.1.get_owned(index % width)?,
+            ))
+        }
+    }
+}
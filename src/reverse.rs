@@ -1,6 +1,7 @@
-use crate::{Slice, SliceBorrowed, SliceMut, SliceOwned, Unique};
+use crate::{RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned, SliceRawMut, Unique};
 
 /// A reversed slice; see [`Slice::rev`].
+#[derive(Clone, Copy)]
 pub struct Reverse<S>(pub S);
 
 impl<S> Slice for Reverse<S>
@@ -47,3 +48,43 @@ where
 
 // SAFETY: the underlying slice is `Unique`, and reversing cannot alias
 unsafe impl<S> Unique for Reverse<S> where S: Unique {}
+
+// SAFETY: the underlying slice is only reached through a raw pointer to it, and
+// its handle upholds the rest
+unsafe impl<S> SliceRawMut for Reverse<S>
+where
+    S: SliceRawMut,
+{
+    type Raw = Reverse<S::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe { Reverse(S::raw_mut(&raw mut (*this).0)) }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and the underlying
+// handle upholds the rest
+unsafe impl<R> RawSlice for Reverse<R>
+where
+    R: RawSlice,
+{
+    type Output = R::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller
+        unsafe { self.0.len() }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            let len = self.0.len();
+            if index >= len {
+                None
+            } else {
+                self.0.get_mut(len - 1 - index)
+            }
+        }
+    }
+}
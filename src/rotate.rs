@@ -0,0 +1,129 @@
+use crate::{RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned, SliceRawMut, Unique};
+
+/// A rotated slice; see [`Slice::rotated_left`] and [`Slice::rotated_right`].
+#[derive(Clone, Copy, Hash)]
+pub struct Rotate<S> {
+    data: S,
+    mid: usize,
+}
+
+impl<S> Rotate<S>
+where
+    S: Slice,
+{
+    /// Creates a slice rotated `k` elements to the left; see
+    /// [`Slice::rotated_left`].
+    pub fn new(data: S, k: usize) -> Self {
+        let len = data.len();
+        let mid = if len == 0 { 0 } else { k % len };
+
+        Self { data, mid }
+    }
+
+    fn translate(&self, index: usize) -> Option<usize> {
+        let len = self.data.len();
+        if index >= len {
+            None
+        } else if index < len - self.mid {
+            Some(index + self.mid)
+        } else {
+            Some(index - (len - self.mid))
+        }
+    }
+}
+
+impl<S> Slice for Rotate<S>
+where
+    S: Slice,
+{
+    type Output = S::Output;
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        self.data.get_with(self.translate(index)?, f)
+    }
+}
+
+impl<S> SliceOwned for Rotate<S>
+where
+    S: SliceOwned,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        self.data.get_owned(self.translate(index)?)
+    }
+}
+
+impl<S> SliceBorrowed for Rotate<S>
+where
+    S: SliceBorrowed,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        self.data.get(self.translate(index)?)
+    }
+}
+
+impl<S> SliceMut for Rotate<S>
+where
+    S: SliceMut,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        let i = self.translate(index)?;
+        self.data.get_mut(i)
+    }
+}
+
+// SAFETY: the underlying slice is `Unique`, and rotation maps each index to a
+// distinct element
+unsafe impl<S> Unique for Rotate<S> where S: Unique {}
+
+// SAFETY: the underlying slice is only reached through a raw pointer to it, and
+// its handle upholds the rest
+unsafe impl<S> SliceRawMut for Rotate<S>
+where
+    S: SliceRawMut,
+{
+    type Raw = Rotate<S::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            Rotate {
+                data: S::raw_mut(&raw mut (*this).data),
+                mid: (*this).mid,
+            }
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and the underlying
+// handle upholds the rest
+unsafe impl<R> RawSlice for Rotate<R>
+where
+    R: RawSlice,
+{
+    type Output = R::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller
+        unsafe { self.data.len() }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            let len = self.data.len();
+            let i = if index >= len {
+                return None;
+            } else if index < len - self.mid {
+                index + self.mid
+            } else {
+                index - (len - self.mid)
+            };
+
+            self.data.get_mut(i)
+        }
+    }
+}
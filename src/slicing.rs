@@ -1,7 +1,7 @@
 use core::marker::PhantomData;
 use core::ops::{Bound, RangeBounds};
 
-use crate::{Slice, SliceBorrowed, SliceMut, SliceOwned, Unique};
+use crate::{RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned, SliceRawMut, StepBy, Unique};
 
 /// A sub-slice of a [`Slice`]; see [`Slice::slice`].
 #[derive(Clone, Copy, Hash)]
@@ -134,40 +134,107 @@ where
 // SAFETY: the underlying slice is `Unique` and owned
 unsafe impl<A> Unique for SliceOf<A> where A: Unique {}
 
+// SAFETY: the underlying slice is only reached through a raw pointer to it, and
+// its handle upholds the rest
+unsafe impl<A> SliceRawMut for SliceOf<A>
+where
+    A: SliceRawMut,
+{
+    type Raw = SliceOf<A::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            SliceOf {
+                data: A::raw_mut(&raw mut (*this).data),
+                start: (*this).start,
+                len: (*this).len,
+            }
+        }
+    }
+}
+
+impl<R> SliceOf<R>
+where
+    R: RawSlice,
+{
+    /// Creates a handle to `len` items of `data`, starting at `start`.
+    fn raw(data: R, start: usize, len: usize) -> Self {
+        Self {
+            data,
+            start: Bound::Included(start),
+            len,
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and the underlying
+// handle upholds the rest
+unsafe impl<R> RawSlice for SliceOf<R>
+where
+    R: RawSlice,
+{
+    type Output = R::Output;
+
+    unsafe fn len(&self) -> usize {
+        self.len
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        if index >= self.len {
+            None
+        } else {
+            let i = match self.start {
+                Bound::Included(s) => index + s,
+                Bound::Excluded(s) => index + s + 1,
+                _ => unreachable!(),
+            };
+
+            // SAFETY: guaranteed by the caller
+            unsafe { self.data.get_mut(i) }
+        }
+    }
+}
+
 /// A mutable sub-slice of a [`Slice`]; see [`SliceMut::split_mut`].
-#[derive(Clone, Copy, Hash)]
-pub struct SplitMut<'a, A: ?Sized> {
-    data: *mut A,
+///
+/// Only a [`RawSlice`] handle to the parent is kept, captured once when it's
+/// split, so each piece only ever borrows its own items.
+pub struct SplitMut<'a, A: SliceRawMut + ?Sized> {
+    data: A::Raw,
 
-    start: Bound<usize>,
+    start: usize,
     len: usize,
 
     _lifetime: PhantomData<&'a mut A>,
 }
 
-impl<A> SplitMut<'_, A>
+impl<'a, A> SplitMut<'a, A>
 where
-    A: Slice + ?Sized,
+    A: SliceRawMut + ?Sized,
 {
     /// Creates a mutable split of the slice; see [`SliceMut::split_mut`].
-    pub fn new(data: &mut A, at: usize) -> Option<(Self, Self)> {
+    pub fn new(data: &'a mut A, at: usize) -> Option<(Self, Self)> {
         let len = data.len();
         if at >= len {
             None
         } else {
+            // SAFETY: `data` is borrowed for `'a`, and is only reached through
+            // the handle until then
+            let data = unsafe { A::raw_mut(data) };
             Some((
                 Self {
-                    data: data as *mut A,
+                    data,
 
-                    start: Bound::Included(0),
+                    start: 0,
                     len: at + 1,
 
                     _lifetime: PhantomData,
                 },
                 Self {
-                    data: data as *mut A,
+                    data,
 
-                    start: Bound::Excluded(at),
+                    start: at + 1,
                     len: len - at - 1,
 
                     _lifetime: PhantomData,
@@ -176,20 +243,99 @@ where
         }
     }
 
-    fn data_imm(&self) -> &A {
-        // SAFETY: lifetimes are guaranteed, `Unique` ensures no aliasing
-        unsafe { &*self.data }
+    /// Splits the slice into `N` disjoint pieces, where each ends at the
+    /// matching entry of `at`; see [`SliceMut::split_many_mut`].
+    pub fn new_many<const N: usize>(data: &'a mut A, at: [usize; N]) -> Option<[Self; N]> {
+        let len = data.len();
+        if <[usize]>::windows(&at, 2).any(|w| w[0] > w[1])
+            || at.last().is_some_and(|&end| end > len)
+        {
+            return None;
+        }
+
+        // SAFETY: as above
+        let data = unsafe { A::raw_mut(data) };
+        Some(core::array::from_fn(|i| {
+            let start = if i == 0 { 0 } else { at[i - 1] };
+            Self {
+                data,
+
+                start,
+                len: at[i] - start,
+
+                _lifetime: PhantomData,
+            }
+        }))
+    }
+
+    /// Splits the slice into pieces of `size` items each, except the last,
+    /// which may be shorter.
+    #[cfg(feature = "std")]
+    pub(crate) fn chunks(data: &'a mut A, size: usize) -> impl Iterator<Item = Self> {
+        let len = data.len();
+        // SAFETY: as above
+        let data = unsafe { A::raw_mut(data) };
+
+        Iterator::step_by(0..len, size).map(move |start| Self {
+            data,
+
+            start,
+            len: size.min(len - start),
+
+            _lifetime: PhantomData,
+        })
+    }
+
+    /// Splits off the first `n` items (or all of them, if there are fewer),
+    /// leaving the rest in `self`.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn take_front(&mut self, n: usize) -> Self {
+        let n = n.min(self.len);
+        let front = Self {
+            data: self.data,
+
+            start: self.start,
+            len: n,
+
+            _lifetime: PhantomData,
+        };
+
+        self.start += n;
+        self.len -= n;
+        front
     }
 
-    fn data_mut(&mut self) -> &mut A {
-        // SAFETY: lifetimes are guaranteed, `Unique` ensures no aliasing
-        unsafe { &mut *self.data }
+    /// Splits off the last `n` items (or all of them, if there are fewer),
+    /// leaving the rest in `self`.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn take_back(&mut self, n: usize) -> Self {
+        let n = n.min(self.len);
+        self.len -= n;
+
+        Self {
+            data: self.data,
+
+            start: self.start + self.len,
+            len: n,
+
+            _lifetime: PhantomData,
+        }
+    }
+
+    fn item(&self, index: usize) -> Option<*mut A::Output> {
+        if index >= self.len {
+            None
+        } else {
+            // SAFETY: the parent outlives `'a`, and is only ever reached
+            // through handles while it's split
+            unsafe { self.data.get_mut(self.start + index) }
+        }
     }
 }
 
 impl<A> Slice for SplitMut<'_, A>
 where
-    A: Slice + ?Sized,
+    A: SliceRawMut + ?Sized,
 {
     type Output = A::Output;
 
@@ -198,74 +344,204 @@ where
     }
 
     fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
-        if index > self.len() {
-            None
-        } else {
-            self.data_imm().get_with(
-                match self.start {
-                    Bound::Included(s) => index + s,
-                    Bound::Excluded(s) => index + s + 1,
-                    _ => unreachable!(),
-                },
-                f,
-            )
-        }
+        Some(f(self.get(index)?))
     }
 }
 
 impl<A> SliceBorrowed for SplitMut<'_, A>
 where
-    A: SliceBorrowed + ?Sized,
+    A: SliceRawMut + ?Sized,
 {
     fn get(&self, index: usize) -> Option<&Self::Output> {
-        let i = match self.start {
-            Bound::Included(s) => index + s,
-            Bound::Excluded(s) => index + s + 1,
-            _ => unreachable!(),
-        };
-
-        if index > self.len {
-            None
-        } else {
-            self.data_imm().get(i)
-        }
+        // SAFETY: the item belongs to this piece only, and is borrowed through
+        // `self`
+        self.item(index).map(|ptr| unsafe { &*ptr })
     }
 }
 
 impl<A> SliceOwned for SplitMut<'_, A>
 where
-    A: SliceOwned + ?Sized,
+    A: SliceRawMut + ?Sized,
+    A::Output: Clone,
 {
     fn get_owned(&self, index: usize) -> Option<Self::Output> {
-        let i = match self.start {
-            Bound::Included(s) => index + s,
-            Bound::Excluded(s) => index + s + 1,
-            _ => unreachable!(),
-        };
+        self.get(index).cloned()
+    }
+}
 
-        if index > self.len {
+impl<A> SliceMut for SplitMut<'_, A>
+where
+    A: SliceRawMut + ?Sized,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        // SAFETY: the item belongs to this piece only, and is borrowed through
+        // `self`
+        self.item(index).map(|ptr| unsafe { &mut *ptr })
+    }
+}
+
+// SAFETY: the parent is `Unique`, and each index maps to a distinct item of it
+unsafe impl<A> Unique for SplitMut<'_, A> where A: SliceRawMut + ?Sized {}
+
+// SAFETY: a piece only hands out its own items, and only reads the parent
+// itself, so other pieces may do the same from other threads
+unsafe impl<A> Send for SplitMut<'_, A>
+where
+    A: SliceRawMut + Sync + ?Sized,
+    A::Output: Send,
+{
+}
+
+// SAFETY: see above; a shared piece only hands out shared items
+unsafe impl<A> Sync for SplitMut<'_, A>
+where
+    A: SliceRawMut + Sync + ?Sized,
+    A::Output: Sync,
+{
+}
+
+// SAFETY: only the piece's own fields are read, and the parent's handle
+// upholds the rest
+unsafe impl<A> SliceRawMut for SplitMut<'_, A>
+where
+    A: SliceRawMut + ?Sized,
+{
+    type Raw = SliceOf<A::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe { SliceOf::raw((*this).data, (*this).start, (*this).len) }
+    }
+}
+
+/// One strided lane of a deinterleaved slice, seeing every `N`th item; see
+/// [`SliceMut::deinterleave`].
+pub struct Lane<'a, A: SliceRawMut + ?Sized> {
+    data: A::Raw,
+
+    offset: usize,
+    step: usize,
+    len: usize,
+
+    _lifetime: PhantomData<&'a mut A>,
+}
+
+impl<'a, A> Lane<'a, A>
+where
+    A: SliceRawMut + ?Sized,
+{
+    /// Splits the slice into `N` lanes; see [`SliceMut::deinterleave`].
+    pub fn new<const N: usize>(data: &'a mut A) -> [Self; N] {
+        let len = data.len();
+        // SAFETY: `data` is borrowed for `'a`, and is only reached through the
+        // handle until then
+        let data = unsafe { A::raw_mut(data) };
+
+        core::array::from_fn(|offset| Self {
+            data,
+
+            offset,
+            step: N,
+            len: len.saturating_sub(offset).div_ceil(N),
+
+            _lifetime: PhantomData,
+        })
+    }
+
+    fn item(&self, index: usize) -> Option<*mut A::Output> {
+        if index >= self.len {
             None
         } else {
-            self.data_imm().get_owned(i)
+            // SAFETY: the parent outlives `'a`, and is only ever reached
+            // through handles while it's split
+            unsafe { self.data.get_mut(self.offset + index * self.step) }
         }
     }
 }
 
-impl<A> SliceMut for SplitMut<'_, A>
+impl<A> Slice for Lane<'_, A>
 where
-    A: SliceMut + Unique,
+    A: SliceRawMut + ?Sized,
+{
+    type Output = A::Output;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        Some(f(self.get(index)?))
+    }
+}
+
+impl<A> SliceBorrowed for Lane<'_, A>
+where
+    A: SliceRawMut + ?Sized,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        // SAFETY: the item belongs to this lane only, and is borrowed through
+        // `self`
+        self.item(index).map(|ptr| unsafe { &*ptr })
+    }
+}
+
+impl<A> SliceOwned for Lane<'_, A>
+where
+    A: SliceRawMut + ?Sized,
+    A::Output: Clone,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        self.get(index).cloned()
+    }
+}
+
+impl<A> SliceMut for Lane<'_, A>
+where
+    A: SliceRawMut + ?Sized,
 {
     fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
-        let i = match self.start {
-            Bound::Included(s) => index + s,
-            Bound::Excluded(s) => index + s + 1,
-            _ => unreachable!(),
-        };
+        // SAFETY: the item belongs to this lane only, and is borrowed through
+        // `self`
+        self.item(index).map(|ptr| unsafe { &mut *ptr })
+    }
+}
 
-        if index > self.len() {
-            None
-        } else {
-            self.data_mut().get_mut(i)
+// SAFETY: the parent is `Unique`, and each index maps to a distinct item of it
+unsafe impl<A> Unique for Lane<'_, A> where A: SliceRawMut + ?Sized {}
+
+// SAFETY: a lane only hands out its own items, and only reads the parent
+// itself, so other lanes may do the same from other threads
+unsafe impl<A> Send for Lane<'_, A>
+where
+    A: SliceRawMut + Sync + ?Sized,
+    A::Output: Send,
+{
+}
+
+// SAFETY: see above; a shared lane only hands out shared items
+unsafe impl<A> Sync for Lane<'_, A>
+where
+    A: SliceRawMut + Sync + ?Sized,
+    A::Output: Sync,
+{
+}
+
+// SAFETY: only the lane's own fields are read, and the parent's handle upholds
+// the rest
+unsafe impl<A> SliceRawMut for Lane<'_, A>
+where
+    A: SliceRawMut + ?Sized,
+{
+    type Raw = StepBy<SliceOf<A::Raw>>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            let step = (*this).step;
+            StepBy {
+                data: SliceOf::raw((*this).data, (*this).offset, (*this).len * step),
+                step,
+            }
         }
     }
 }
@@ -0,0 +1,306 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{Slice, SliceMut, Unique};
+
+/// Below this length, sorting and selection fall back to insertion sort.
+const INSERTION_THRESHOLD: usize = 16;
+
+/// Compares two elements of a slice.
+///
+/// # Panics
+///
+/// If either index is out-of-bounds, panics.
+pub(crate) fn compare<S, F>(slice: &S, a: usize, b: usize, cmp: &mut F) -> Ordering
+where
+    S: Slice + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    slice
+        .get_with(a, &mut |x| slice.get_with(b, &mut |y| cmp(x, y)))
+        .flatten()
+        .unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                slice.len(),
+                a.max(b)
+            )
+        })
+}
+
+/// Swaps an element of a slice with an element of a scratch buffer.
+fn swap_with<S, B>(slice: &mut S, a: usize, scratch: &mut B, b: usize)
+where
+    S: SliceMut + ?Sized,
+    B: SliceMut<Output = S::Output> + ?Sized,
+{
+    core::mem::swap(slice.get_mut(a).unwrap(), scratch.get_mut(b).unwrap());
+}
+
+/// Compares an element of a slice with an element of a scratch buffer.
+fn compare_with<S, B, F>(slice: &S, a: usize, scratch: &B, b: usize, cmp: &mut F) -> Ordering
+where
+    S: Slice + ?Sized,
+    B: Slice<Output = S::Output> + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    slice
+        .get_with(a, &mut |x| scratch.get_with(b, &mut |y| cmp(x, y)))
+        .flatten()
+        .unwrap()
+}
+
+fn is_less<S, F>(slice: &S, a: usize, b: usize, cmp: &mut F) -> bool
+where
+    S: Slice + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    compare(slice, a, b, cmp) == Ordering::Less
+}
+
+/// Sorts `slice[lo..hi]` with insertion sort.
+fn insertion_sort<S, F>(slice: &mut S, lo: usize, hi: usize, cmp: &mut F)
+where
+    S: SliceMut + Unique + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    for i in lo + 1..hi {
+        let mut j = i;
+        while j > lo && is_less(slice, j, j - 1, cmp) {
+            slice.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+/// Restores the heap property for the heap `slice[lo..hi]`, starting at
+/// `lo + node`.
+fn sift_down<S, F>(slice: &mut S, lo: usize, hi: usize, mut node: usize, cmp: &mut F)
+where
+    S: SliceMut + Unique + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    let len = hi - lo;
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= len {
+            break;
+        }
+
+        if child + 1 < len && is_less(slice, lo + child, lo + child + 1, cmp) {
+            child += 1;
+        }
+
+        if !is_less(slice, lo + node, lo + child, cmp) {
+            break;
+        }
+
+        slice.swap(lo + node, lo + child);
+        node = child;
+    }
+}
+
+/// Sorts `slice[lo..hi]` with heapsort, which is `O(n log n)` in the worst
+/// case and needs no extra memory.
+pub(crate) fn heapsort<S, F>(slice: &mut S, lo: usize, hi: usize, cmp: &mut F)
+where
+    S: SliceMut + Unique + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    let len = hi - lo;
+    for node in Iterator::rev(0..len / 2) {
+        sift_down(slice, lo, hi, node, cmp);
+    }
+
+    for end in Iterator::rev(1..len) {
+        slice.swap(lo, lo + end);
+        sift_down(slice, lo, lo + end, 0, cmp);
+    }
+}
+
+/// Reorders the slice such that `slice[nth]` is in its sorted position, with
+/// quickselect falling back to heapsort if partitioning goes badly.
+pub(crate) fn select<S, F>(slice: &mut S, nth: usize, cmp: &mut F)
+where
+    S: SliceMut + Unique + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    let (mut lo, mut hi) = (0, slice.len());
+    let mut limit = usize::BITS - hi.leading_zeros();
+
+    loop {
+        if hi - lo <= INSERTION_THRESHOLD {
+            insertion_sort(slice, lo, hi, cmp);
+            return;
+        }
+
+        if limit == 0 {
+            heapsort(slice, lo, hi, cmp);
+            return;
+        }
+        limit -= 1;
+
+        // Move the median of three to the front, to use as the pivot
+        let mid = lo + (hi - lo) / 2;
+        if is_less(slice, mid, lo, cmp) {
+            slice.swap(mid, lo);
+        }
+        if is_less(slice, hi - 1, mid, cmp) {
+            slice.swap(hi - 1, mid);
+            if is_less(slice, mid, lo, cmp) {
+                slice.swap(mid, lo);
+            }
+        }
+        slice.swap(lo, mid);
+
+        let mut store = lo + 1;
+        for i in lo + 1..hi {
+            if is_less(slice, i, lo, cmp) {
+                slice.swap(i, store);
+                store += 1;
+            }
+        }
+
+        let pivot = store - 1;
+        slice.swap(lo, pivot);
+
+        match nth.cmp(&pivot) {
+            Ordering::Less => hi = pivot,
+            Ordering::Equal => return,
+            Ordering::Greater => lo = pivot + 1,
+        }
+    }
+}
+
+/// Stably merges the sorted runs `slice[lo..mid]` and `slice[mid..hi]`,
+/// swapping the shorter run into `scratch`.
+fn merge<S, B, F>(slice: &mut S, scratch: &mut B, lo: usize, mid: usize, hi: usize, cmp: &mut F)
+where
+    S: SliceMut + Unique + ?Sized,
+    B: SliceMut<Output = S::Output> + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    if !is_less(slice, mid, mid - 1, cmp) {
+        return;
+    }
+
+    if mid - lo <= hi - mid {
+        // Merge forwards, filling the gap left by the left run
+        let n = mid - lo;
+        for i in 0..n {
+            swap_with(slice, lo + i, scratch, i);
+        }
+
+        let (mut i, mut j, mut k) = (0, mid, lo);
+        while i < n && j < hi {
+            if compare_with(slice, j, scratch, i, cmp) == Ordering::Less {
+                slice.swap(k, j);
+                j += 1;
+            } else {
+                swap_with(slice, k, scratch, i);
+                i += 1;
+            }
+            k += 1;
+        }
+
+        while i < n {
+            swap_with(slice, k, scratch, i);
+            i += 1;
+            k += 1;
+        }
+    } else {
+        // Merge backwards, filling the gap left by the right run
+        let n = hi - mid;
+        for i in 0..n {
+            swap_with(slice, mid + i, scratch, i);
+        }
+
+        let (mut i, mut j, mut k) = (n, mid, hi);
+        while i > 0 && j > lo {
+            k -= 1;
+            if compare_with(slice, j - 1, scratch, i - 1, cmp) == Ordering::Greater {
+                slice.swap(k, j - 1);
+                j -= 1;
+            } else {
+                swap_with(slice, k, scratch, i - 1);
+                i -= 1;
+            }
+        }
+
+        while i > 0 {
+            k -= 1;
+            swap_with(slice, k, scratch, i - 1);
+            i -= 1;
+        }
+    }
+}
+
+/// Stably sorts the slice with a bottom-up merge sort, using `scratch` to
+/// hold runs while merging.
+///
+/// # Panics
+///
+/// If `scratch` is shorter than half the slice, panics.
+pub(crate) fn merge_sort<S, B, F>(slice: &mut S, scratch: &mut B, cmp: &mut F)
+where
+    S: SliceMut + Unique + ?Sized,
+    B: SliceMut<Output = S::Output> + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    let len = slice.len();
+    if scratch.len() < len / 2 {
+        panic!(
+            "scratch buffer is too small: the len is {} but the slice needs {}",
+            scratch.len(),
+            len / 2
+        );
+    }
+
+    let mut lo = 0;
+    while lo < len {
+        let hi = len.min(lo + INSERTION_THRESHOLD);
+        insertion_sort(slice, lo, hi, cmp);
+        lo = hi;
+    }
+
+    let mut width = INSERTION_THRESHOLD;
+    while width < len {
+        let mut lo = 0;
+        while len - lo > width {
+            let mid = lo + width;
+            let hi = len.min(mid + width);
+            merge(slice, scratch, lo, mid, hi, cmp);
+            lo = hi;
+        }
+
+        width = width.saturating_mul(2);
+    }
+}
+
+/// Stably sorts the slice by sorting its indices, then applying the resulting
+/// permutation in-place.
+#[cfg(feature = "alloc")]
+pub(crate) fn index_sort<S, F>(slice: &mut S, cmp: &mut F)
+where
+    S: SliceMut + Unique + ?Sized,
+    F: FnMut(&S::Output, &S::Output) -> Ordering,
+{
+    let mut indices: Vec<usize> = (0..slice.len()).collect();
+    <[usize]>::sort_by(&mut indices, |&a, &b| compare(slice, a, b, cmp));
+
+    // Element `indices[i]` belongs at `i`; follow each cycle of the
+    // permutation, marking positions as done once they're filled
+    for start in 0..indices.len() {
+        let mut current = start;
+        while indices[current] != current {
+            let next = core::mem::replace(&mut indices[current], current);
+            if next == start {
+                break;
+            }
+
+            slice.swap(current, next);
+            current = next;
+        }
+    }
+}
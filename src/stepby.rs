@@ -0,0 +1,132 @@
+use crate::{RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned, SliceRawMut, Unique};
+
+/// A slice over every `n`th element of another; see [`Slice::step_by`].
+#[derive(Clone, Copy, Hash)]
+pub struct StepBy<S> {
+    pub(crate) data: S,
+    pub(crate) step: usize,
+}
+
+impl<S> StepBy<S>
+where
+    S: Slice,
+{
+    /// Creates a stepped slice; see [`Slice::step_by`].
+    ///
+    /// # Panics
+    ///
+    /// If `step == 0`, panics.
+    pub fn new(data: S, step: usize) -> Self {
+        if step == 0 {
+            panic!("cannot call `step_by` with step = 0");
+        }
+
+        Self { data, step }
+    }
+}
+
+impl<S> Slice for StepBy<S>
+where
+    S: Slice,
+{
+    type Output = S::Output;
+
+    fn len(&self) -> usize {
+        self.data.len().div_ceil(self.step)
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        if index >= self.len() {
+            None
+        } else {
+            self.data.get_with(index * self.step, f)
+        }
+    }
+}
+
+impl<S> SliceOwned for StepBy<S>
+where
+    S: SliceOwned,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        if index >= self.len() {
+            None
+        } else {
+            self.data.get_owned(index * self.step)
+        }
+    }
+}
+
+impl<S> SliceBorrowed for StepBy<S>
+where
+    S: SliceBorrowed,
+{
+    fn get(&self, index: usize) -> Option<&Self::Output> {
+        if index >= self.len() {
+            None
+        } else {
+            self.data.get(index * self.step)
+        }
+    }
+}
+
+impl<S> SliceMut for StepBy<S>
+where
+    S: SliceMut,
+{
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+        if index >= self.len() {
+            None
+        } else {
+            self.data.get_mut(index * self.step)
+        }
+    }
+}
+
+// SAFETY: the underlying slice is `Unique`, and `step` is non-zero, so no two
+// indices map to the same element
+unsafe impl<S> Unique for StepBy<S> where S: Unique {}
+
+// SAFETY: the underlying slice is only reached through a raw pointer to it, and
+// its handle upholds the rest
+unsafe impl<S> SliceRawMut for StepBy<S>
+where
+    S: SliceRawMut,
+{
+    type Raw = StepBy<S::Raw>;
+
+    unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            StepBy {
+                data: S::raw_mut(&raw mut (*this).data),
+                step: (*this).step,
+            }
+        }
+    }
+}
+
+// SAFETY: indices are mapped the same way as in `get_mut`, and the underlying
+// handle upholds the rest
+unsafe impl<R> RawSlice for StepBy<R>
+where
+    R: RawSlice,
+{
+    type Output = R::Output;
+
+    unsafe fn len(&self) -> usize {
+        // SAFETY: guaranteed by the caller
+        unsafe { self.data.len().div_ceil(self.step) }
+    }
+
+    unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+        // SAFETY: guaranteed by the caller
+        unsafe {
+            if index >= self.len() {
+                None
+            } else {
+                self.data.get_mut(index * self.step)
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Slice, SliceBorrowed, SliceOwned};
+use crate::{Slice, SliceBorrowed, SliceMut, SliceOwned, SliceRawMut};
 
 #[test]
 fn debug_impl() {
@@ -60,3 +60,771 @@ fn ranges() {
     assert_eq!(range.get_owned(4), None);
     assert_eq!(Slice::rev(range).get_owned(0), Some(4));
 }
+
+#[test]
+fn step_by() {
+    let slice = [1, 2, 3, 4, 5, 6, 7];
+    assert_eq!(slice.step_by(1), slice);
+    assert_eq!(slice.step_by(3), [1, 4, 7]);
+    assert_eq!(slice.step_by(3).get(3), None);
+    assert_eq!(slice.step_by(7), [1]);
+    assert_eq!(slice.step_by(100), [1]);
+    assert!([0; 0].step_by(2).is_empty());
+
+    let mut data = [9, 0, 7, 0, 5, 0, 3];
+    let mut stepped = (&mut data).step_by(2);
+    stepped.sort_unstable();
+    assert_eq!(data, [3, 0, 5, 0, 7, 0, 9]);
+
+    let mut stepped = (&mut data).step_by(2);
+    let (mut left, mut right) = stepped.split_mut(1).unwrap();
+    core::mem::swap(&mut left[1], &mut right[1]);
+    assert_eq!(data, [3, 0, 9, 0, 7, 0, 5]);
+}
+
+#[test]
+#[should_panic = "cannot call `step_by` with step = 0"]
+fn step_by_zero() {
+    let _ = [1, 2, 3].step_by(0);
+}
+
+#[test]
+fn rotate() {
+    let empty: [i32; 0] = [];
+    assert!(empty.rotated_left(3).is_empty());
+    assert!(empty.rotated_right(3).is_empty());
+
+    let slice = [1, 2, 3];
+    assert_eq!(slice.rotated_left(7), [2, 3, 1]);
+    assert_eq!(slice.rotated_right(7), [3, 1, 2]);
+    assert_eq!(slice.rotated_left(3).get(3), None);
+
+    // with `Slice` in scope, these must still rotate in-place
+    let mut arr = [1, 2, 3, 4];
+    arr.rotate_left(1);
+    assert_eq!(arr, [2, 3, 4, 1]);
+    arr.rotate_right(3);
+    assert_eq!(arr, [3, 4, 1, 2]);
+}
+
+#[test]
+fn product() {
+    let slice = [1, 2].product([3, 4, 5]);
+    let mut chunks = slice.chunks(3);
+    assert_eq!(chunks.next().unwrap(), [(1, 3), (1, 4), (1, 5)]);
+    assert_eq!(chunks.next().unwrap(), [(2, 3), (2, 4), (2, 5)]);
+    assert!(chunks.next().is_none());
+
+    assert_eq!(slice.get_owned(6), None);
+    assert!([1, 2].product([0; 0]).is_empty());
+    assert_eq!([1, 2].product([0; 0]).get_owned(0), None);
+
+    let infinite = [1, 2].product([3].cycle());
+    assert_eq!(infinite.len(), usize::MAX);
+    assert_eq!(infinite.get_owned(5), Some((1, 3)));
+    assert_eq!([3].cycle().product([1, 2]).get_owned(5), Some((3, 2)));
+}
+
+#[test]
+fn permute() {
+    let slice = [1, 2, 3];
+    assert!(slice.permute([0, 1]).is_none());
+    assert!(slice.permute([0, 1, 3]).is_none());
+    assert!(slice.permute([2, 1, 2]).is_none());
+
+    let mut data = [1, 2, 3, 4];
+    let mut permuted = (&mut data).permute([3, 1, 2, 0]).unwrap();
+    let (mut left, mut right) = permuted.split_mut(1).unwrap();
+    core::mem::swap(&mut left[0], &mut right[1]);
+    assert_eq!([1, 2, 3, 4].gather([3, 1, 2, 0]), data);
+}
+
+#[test]
+fn sorting() {
+    let mut a = [
+        9, 3, 7, 1, 8, 2, 2, 6, 0, 5, 4, 11, 15, 13, 12, 10, 14, 17, 16, 19, 18,
+    ];
+    let mut b = [-1, 30, 25, 20, -3];
+    let mut slice = (&mut a).chain(&mut b);
+
+    for i in 0..slice.len() {
+        let nth = *slice.select_nth_unstable(i);
+        let mut sorted = [0; 26];
+        sorted.copy_from_slice(&slice);
+        sorted.sort_unstable();
+        assert_eq!(nth, sorted[i]);
+    }
+
+    slice.sort_unstable_by_key(|x: &i32| x.abs());
+    assert!((0..slice.len() - 1).all(|i| slice[i].abs() <= slice[i + 1].abs()));
+
+    let mut empty: [i32; 0] = [];
+    (&mut empty).rev().sort_unstable();
+
+    // references to contiguous slices still reach `core`'s sorts
+    let mut data = [3, 1, 2];
+    let mut by_ref = &mut data;
+    assert!((&mut by_ref).as_contiguous_mut().is_some());
+    (&mut by_ref).sort_unstable();
+    assert_eq!(data, [1, 2, 3]);
+}
+
+#[test]
+fn stable_sorting() {
+    let mut seed = 0x2545_f491_u32;
+    let mut data = [(0_u32, 0_usize); 100];
+    for (i, x) in data.iter_mut().enumerate() {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        *x = ((seed >> 16) % 8, i);
+    }
+
+    for len in [0, 1, 15, 16, 17, 33, 64, 100] {
+        let mut expected = data;
+        expected[..len].sort_by_key(|x| x.0);
+
+        let mut sorted = data;
+        let (a, b) = sorted[..len].split_at_mut(len / 3);
+        let mut scratch = [(0, 0); 50];
+        a.chain(b)
+            .sort_by_with_buffer(&mut scratch[..len / 2], |x, y| x.0.cmp(&y.0));
+        assert_eq!(sorted, expected);
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut sorted = data;
+            let (a, b) = sorted[..len].split_at_mut(len / 3);
+            a.chain(b).sort_by_key(|x| x.0);
+            assert_eq!(sorted, expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic = "scratch buffer is too small: the len is 1 but the slice needs 2"]
+fn stable_sorting_buffer() {
+    let mut slice = [4, 3, 2, 1];
+    slice.sort_by_with_buffer(&mut [0], Ord::cmp);
+}
+
+#[test]
+fn binary_search() {
+    let empty: [i32; 0] = [];
+    assert_eq!(empty.binary_search(&1), Err(0));
+
+    let slice = [1, 1, 2, 3, 5, 8].slice(1..).unwrap();
+    assert_eq!(slice.binary_search(&8), Ok(4));
+    assert_eq!(slice.binary_search(&0), Err(0));
+    assert_eq!(slice.partition_point(|&x| x < 2), 1);
+
+    assert_eq!((0..100).binary_search(&42), Ok(42));
+}
+
+#[test]
+fn mutators() {
+    let mut slice = [1, 2, 3, 4, 5, 6, 7];
+    let mut odd = (&mut slice).step_by(2);
+
+    odd.rotate_left(5);
+    assert_eq!(odd, [3, 5, 7, 1]);
+    odd.rotate_right(1);
+    assert_eq!(odd, [1, 3, 5, 7]);
+
+    odd.copy_within(..3, 1);
+    assert_eq!(odd, [1, 1, 3, 5]);
+    odd.copy_within(2.., 0);
+    assert_eq!(odd, [3, 5, 3, 5]);
+    assert_eq!(slice, [3, 2, 5, 4, 3, 6, 5]);
+
+    let mut empty: [i32; 0] = [];
+    empty.rotate_left(1);
+    empty.copy_within(.., 0);
+
+    // contiguous slices go straight to `core`, with the same semantics
+    let mut data = [1, 2, 3, 4, 5];
+    SliceMut::rotate_left(&mut data, 7);
+    assert_eq!(data, [3, 4, 5, 1, 2]);
+    SliceMut::rotate_right(&mut data, 8);
+    assert_eq!(data, [5, 1, 2, 3, 4]);
+    SliceMut::reverse(&mut data);
+    assert_eq!(data, [4, 3, 2, 1, 5]);
+    SliceMut::swap(&mut data, 0, 4);
+    assert_eq!(data, [5, 3, 2, 1, 4]);
+    SliceMut::copy_within(&mut data, 1..4, 2);
+    assert_eq!(data, [5, 3, 3, 2, 1]);
+    SliceMut::copy_within(&mut data, 2.., 0);
+    assert_eq!(data, [3, 2, 1, 2, 1]);
+    SliceMut::fill(&mut data[..2], 0);
+    let mut n = 0;
+    SliceMut::fill_with(&mut data[2..], || {
+        n += 1;
+        n
+    });
+    assert_eq!(data, [0, 0, 1, 2, 3]);
+}
+
+#[test]
+#[should_panic = "index out of bounds: the len is 3 but the index is 3"]
+fn swap_bounds() {
+    SliceMut::swap(&mut [1, 2, 3], 0, 3);
+}
+
+#[test]
+#[should_panic = "source range 2..5 is out of bounds for a slice of length 4"]
+fn copy_within_bounds() {
+    let mut slice = [1, 2, 3, 4];
+    slice.copy_within(2..=4, 0);
+}
+
+#[test]
+fn projections() {
+    let pairs = [(1, 'a'), (2, 'b'), (3, 'c')];
+    let numbers = pairs.map_ref(|p| &p.0);
+
+    assert!(numbers.iter().eq(&[1, 2, 3]));
+    assert!(numbers.windows(2).nth(1).unwrap().iter().eq(&[2, 3]));
+    assert_eq!(format!("{numbers:?}"), "[1, 2, 3]");
+}
+
+#[test]
+fn map_mut() {
+    let mut pairs = [(3, 'a'), (1, 'b'), (2, 'c')];
+    let mut numbers = (&mut pairs).map_mut(|p| &p.0, |p| &mut p.0);
+
+    assert_eq!(numbers.len(), 3);
+    assert_eq!(numbers, [3, 1, 2]);
+    assert_eq!(format!("{numbers:?}"), "[3, 1, 2]");
+
+    numbers[0] += 1;
+    *numbers.get_mut(2).unwrap() = 0;
+    numbers.sort_unstable();
+    assert_eq!(numbers, [0, 1, 4]);
+    assert_eq!(pairs, [(0, 'a'), (1, 'b'), (4, 'c')]);
+}
+
+#[test]
+#[should_panic = "`map_mut` projections disagree at index 0"]
+fn map_mut_disagree() {
+    let mut pairs = [(3, 1), (1, 2)];
+    let mut numbers = (&mut pairs).map_mut(|p| &p.0, |p| &mut p.1);
+    numbers.sort_unstable();
+}
+
+#[test]
+fn enumerate() {
+    let slice = [5, 6, 7].chain([8]);
+
+    assert_eq!(
+        SliceBorrowed::enumerate(&slice),
+        [(0, &5), (1, &6), (2, &7), (3, &8)]
+    );
+    assert_eq!(
+        SliceOwned::map_with_index(Slice::rev(10..14), |i, x| x - i),
+        [13, 11, 9, 7]
+    );
+    assert_eq!(
+        format!("{:?}", SliceOwned::enumerate(0..2)),
+        "[(0, 0), (1, 1)]"
+    );
+}
+
+#[test]
+fn zip_mut() {
+    let mut ids = [1, 2, 3];
+    let mut scores = [10, 20, 30, 40];
+    let mut table = (&mut ids).zip_mut(&mut scores);
+
+    assert_eq!(table.len(), 3);
+    for (id, score) in table.iter_mut().rev() {
+        *score += *id;
+        *id *= 100;
+    }
+    assert_eq!(format!("{table:?}"), "[(100, 11), (200, 22), (300, 33)]");
+    assert_eq!(
+        SliceBorrowed::zip(&ids, &scores[..]),
+        [(&100, &11), (&200, &22), (&300, &33)]
+    );
+
+    // earlier pairs stay usable while later ones are yielded
+    let mut table = (&mut ids).zip_mut(&mut scores[..]);
+    let mut pairs = table.iter_mut();
+    let (first_id, first_score) = pairs.next().unwrap();
+    let (last_id, last_score) = pairs.next_back().unwrap();
+    core::mem::swap(first_id, last_id);
+    core::mem::swap(first_score, last_score);
+    assert_eq!(ids, [300, 200, 100]);
+    assert_eq!(scores, [33, 22, 11, 40]);
+
+    let mut empty: [i32; 0] = [];
+    assert!((&mut empty)
+        .zip_mut(&mut scores)
+        .iter_mut()
+        .next()
+        .is_none());
+}
+
+#[test]
+fn unzip() {
+    let mut pairs = [(3, 'a'), (1, 'b'), (2, 'c')];
+
+    let (keys, values) = pairs.unzip();
+    assert_eq!(keys, [3, 1, 2]);
+    assert_eq!(format!("{values:?}"), "['a', 'b', 'c']");
+
+    let mut values = crate::UnzipRight(&mut pairs);
+    values[0] = 'x';
+    values.reverse();
+    assert_eq!(pairs, [(3, 'c'), (1, 'b'), (2, 'x')]);
+
+    let (mut keys, mut values) = pairs.unzip_mut();
+    keys.sort_unstable();
+    values.swap(0, 2);
+    assert_eq!(keys, [1, 2, 3]);
+    assert_eq!(values, ['x', 'b', 'c']);
+    assert_eq!(pairs, [(1, 'x'), (2, 'b'), (3, 'c')]);
+
+    // any `SliceRawMut` can be unzipped, not only contiguous ones
+    let mut more = [(0, 'y')];
+    let mut chained = (&mut pairs).chain(&mut more);
+    let (mut keys, mut values) = chained.unzip_mut();
+    keys.rotate_left(1);
+    values[3] = values[0];
+    assert_eq!(keys, [2, 3, 0, 1]);
+    assert_eq!(pairs, [(2, 'x'), (3, 'b'), (0, 'c')]);
+    assert_eq!(more, [(1, 'x')]);
+
+    // for Miri: reaching one side mustn't invalidate references into the other
+    let mut more = [(4, 'd')];
+    let mut chained = (&mut pairs).chain(&mut more);
+    let (mut keys, mut values) = chained.unzip_mut();
+    let key = keys.get_mut(3).unwrap();
+    let value = values.get_mut(3).unwrap();
+    *key += 1;
+    *value = 'e';
+    assert_eq!(more, [(5, 'e')]);
+}
+
+#[test]
+fn nary_macros() {
+    let mut a = [1, 2];
+    let mut b = [3, 4, 5];
+    let mut chained = crate::chain!(&mut a, &mut [][..], &mut b, [6, 7]);
+
+    assert_eq!(chained.len(), 7);
+    assert_eq!(chained, [1, 2, 3, 4, 5, 6, 7]);
+    chained[3] = 0;
+    assert_eq!(chained.get(5), Some(&6));
+    assert_eq!(chained.get(7), None);
+    assert_eq!(b, [3, 0, 5]);
+
+    let zipped = crate::zip!(0..3, ['a', 'b', 'c'], [0.5, 1.5], "xyz".as_bytes());
+    assert_eq!(zipped.len(), 2);
+    assert_eq!(
+        format!("{zipped:?}"),
+        "[(0, 'a', 0.5, 120), (1, 'b', 1.5, 121)]"
+    );
+}
+
+#[test]
+fn chain_many() {
+    let chained = crate::ChainMany::from([&[1, 2][..], &[], &[3], &[4, 5, 6]]);
+    assert_eq!(chained.len(), 6);
+    assert_eq!(chained, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(chained.get(6), None);
+
+    let mut pages = [[0; 4]; 3];
+    let mut chained = crate::ChainMany::from(pages.each_mut());
+    chained[5] = 1;
+    chained.rotate_right(1);
+    assert_eq!(pages, [[0, 0, 0, 0], [0, 0, 1, 0], [0, 0, 0, 0]]);
+
+    let empty: crate::ChainMany<[[i32; 0]; 0], _> = crate::ChainMany::from([]);
+    assert!(empty.is_empty());
+
+    // an infinite slice saturates the table instead of overflowing it
+    let endless = crate::ChainMany::from([[1].cycle(), [2].cycle()]);
+    assert_eq!(endless.len(), usize::MAX);
+    assert_eq!(endless.get_owned(7), Some(1));
+
+    #[cfg(feature = "alloc")]
+    {
+        let chained = crate::ChainMany::from(vec![0..2, 5..5, 2..4]);
+        assert_eq!(chained, [0, 1, 2, 3]);
+
+        let endless = crate::ChainMany::from(vec![[1].cycle(), [2].cycle()]);
+        assert_eq!(endless.len(), usize::MAX);
+    }
+}
+
+#[test]
+fn flatten() {
+    let mut pixels = [[1, 2, 3, 4], [5, 6, 7, 8]];
+    let mut flat = (&mut pixels).flatten_uniform(4);
+    flat[4] = 0;
+    SliceMut::reverse(&mut flat);
+    assert_eq!(flat, [8, 7, 6, 0, 4, 3, 2, 1]);
+    assert_eq!(pixels, [[8, 7, 6, 0], [4, 3, 2, 1]]);
+
+    let narrow = pixels.flatten_uniform(2);
+    assert_eq!(narrow, [8, 7, 4, 3]);
+    assert_eq!(
+        format!("{:?}", SliceOwned::map(0..2, |_| 0..3).flatten_uniform(3)),
+        "[0, 1, 2, 0, 1, 2]"
+    );
+
+    let endless = [[1, 2]].cycle().flatten_uniform(2);
+    assert_eq!(endless.len(), usize::MAX);
+    assert_eq!(endless.get(5), Some(&2));
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut ragged = vec![vec![], vec![1], vec![], vec![2, 3], vec![]];
+        let mut flat = (&mut ragged).flatten();
+        assert_eq!(flat.len(), 3);
+        assert_eq!(flat, [1, 2, 3]);
+        assert_eq!(flat.get(3), None);
+        flat[2] = 4;
+        assert_eq!(ragged[3], [2, 4]);
+
+        let endless = vec![[1].cycle(), [2].cycle()].flatten();
+        assert_eq!(endless.len(), usize::MAX);
+        assert_eq!(endless.get_owned(3), Some(1));
+    }
+}
+
+#[test]
+#[should_panic = "inner slice 1 is too short: the len is 1 but the width is 2"]
+fn flatten_uniform_short() {
+    let rows: [&[i32]; 2] = [&[1, 2], &[3]];
+    let _ = rows.flatten_uniform(2).get(3);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn join() {
+    let words: [&[char]; 4] = [&['a', 'b'], &[], &['c'], &['d', 'e']];
+    let joined = words.join_with([',', ' ']);
+
+    assert_eq!(joined.len(), 11);
+    assert_eq!(
+        joined,
+        ['a', 'b', ',', ' ', ',', ' ', 'c', ',', ' ', 'd', 'e']
+    );
+    assert_eq!(joined.get(11), None);
+
+    let empty: [&[char]; 0] = [];
+    assert!(empty.join_with([',']).is_empty());
+    assert_eq!([&['a'][..]].join_with([',']), ['a']);
+
+    let mut rows = [[1, 2], [3, 4]];
+    let mut sep = [0];
+    let mut joined = (&mut rows).join_with(&mut sep);
+    joined[2] = 9;
+    joined[3] = 5;
+    assert_eq!(joined, [1, 2, 9, 5, 4]);
+    assert_eq!(rows, [[1, 2], [5, 4]]);
+
+    let endless = [[1].cycle(), [2].cycle()].join_with([0]);
+    assert_eq!(endless.len(), usize::MAX);
+    assert_eq!(endless.get_owned(3), Some(1));
+
+    // Doesn't shadow the standard library's `join`
+    assert_eq!(["a", "b"].join(","), "a,b");
+    let words: Vec<&str> = ["a", "b"].into();
+    assert_eq!(words.join(","), "a,b");
+}
+
+#[test]
+fn interleave() {
+    assert_eq!([1, 2].interleave([3, 4, 5]), [1, 3, 2, 4]);
+    assert_eq!([1, 2, 3].interleave([4]).get_owned(3), None);
+    assert_eq!([1, 2].interleave_longest([3, 4, 5, 6]), [1, 3, 2, 4, 5, 6]);
+
+    let ratio = [1, 2, 3, 4, 5].interleave_ratio([10, 20, 30], 2, 1);
+    assert_eq!(ratio, [1, 2, 10, 3, 4, 20, 5]);
+    let ratio = [1, 2, 3, 4].interleave_ratio([10, 20, 30], 2, 1);
+    assert_eq!(ratio, [1, 2, 10, 3, 4, 20]);
+    let ratio = [1, 2].interleave_ratio([10, 20, 30], 1, 2);
+    assert_eq!(ratio, [1, 10, 20, 2, 30]);
+
+    let mut left = [0; 3];
+    let mut right = [0; 3];
+    let mut stereo = crate::interleave_n([&mut left, &mut right]);
+    for i in 0..stereo.len() {
+        stereo[i] = i;
+    }
+    assert_eq!(left, [0, 2, 4]);
+    assert_eq!(right, [1, 3, 5]);
+
+    let empty: [[i32; 2]; 0] = [];
+    assert!(crate::interleave_n(empty).is_empty());
+    assert_eq!(
+        crate::interleave_n([[1, 2], [3, 4], [5, 6]]),
+        [1, 3, 5, 2, 4, 6]
+    );
+
+    // infinite sides saturate rather than overflow
+    let endless = [1].cycle().interleave([2].cycle());
+    assert_eq!(endless.len(), usize::MAX);
+    assert_eq!(endless.get_owned(3), Some(2));
+    let endless = [1].cycle().interleave_longest([2]);
+    assert_eq!(endless.len(), usize::MAX);
+    assert_eq!(endless.get_owned(2), Some(1));
+    let endless = [1].cycle().interleave_ratio([2].cycle(), 2, 1);
+    assert_eq!(endless.len(), usize::MAX);
+    assert_eq!(endless.get_owned(5), Some(2));
+    assert_eq!(
+        crate::interleave_n([[1].cycle(), [2].cycle()]).len(),
+        usize::MAX
+    );
+}
+
+#[test]
+#[should_panic = "cannot call `interleave_ratio` with a ratio of 0"]
+fn interleave_ratio_zero() {
+    let _ = [1].interleave_ratio([2], 1, 0);
+}
+
+#[test]
+fn deinterleave() {
+    let mut rgba = [10, 20, 30, 255, 11, 21, 31, 255, 12];
+    let [mut r, g, mut b, a] = rgba.deinterleave::<4>();
+
+    assert_eq!((r.len(), g.len(), b.len(), a.len()), (3, 2, 2, 2));
+    assert_eq!(a, [255, 255]);
+    assert_eq!(r.get(3), None);
+    r.swap(0, 2);
+    b[1] = g[0];
+    assert_eq!(format!("{r:?}"), "[12, 11, 10]");
+    assert_eq!(rgba, [12, 20, 30, 255, 11, 21, 20, 255, 10]);
+
+    let [] = rgba.deinterleave::<0>();
+    let mut empty: [i32; 0] = [];
+    let [lane] = empty.deinterleave::<1>();
+    assert!(lane.is_empty());
+}
+
+#[test]
+fn split_many() {
+    let mut buf = [0; 7];
+    let [mut a, mut b, mut c] = buf.split_many_mut([2, 2, 5]).unwrap();
+    assert_eq!((a.len(), b.len(), c.len()), (2, 0, 3));
+    assert_eq!(b.get(0), None);
+    assert_eq!(a.get_mut(2), None);
+
+    a.fill(1);
+    b.fill(2);
+    c.fill(3);
+    c[2] = 4;
+    assert_eq!(buf, [1, 1, 3, 3, 4, 0, 0]);
+
+    assert!(buf.split_many_mut([3, 2]).is_none());
+    assert!(buf.split_many_mut([8]).is_none());
+    let [] = buf.split_many_mut([]).unwrap();
+
+    let mut chained = [1, 2].chain([3, 4]);
+    let [x, y, z] = chained.get_many_mut([3, 0, 2]).unwrap();
+    (*x, *y, *z) = (*y, *z, *x);
+    assert_eq!(chained, [3, 2, 4, 1]);
+    assert!(chained.get_many_mut([0, 1, 0]).is_none());
+}
+
+// Meant to be run under Miri (`cargo +nightly miri test`), to catch the halves
+// of a split invalidating each other's references.
+#[test]
+fn split_mut_aliasing() {
+    let mut data = [0, 1, 2, 3, 4, 5, 6, 7];
+    let (mut left, mut right) = data.split_mut(3).unwrap();
+    let a = left.get_mut(0).unwrap();
+    let b = right.get_mut(0).unwrap();
+    core::mem::swap(a, b);
+    *a += 10;
+    *b += 10;
+
+    let (mut ll, mut lr) = left.split_mut(1).unwrap();
+    let (mut rl, mut rr) = right.split_mut(0).unwrap();
+    let refs = [
+        ll.get_mut(1).unwrap(),
+        lr.get_mut(0).unwrap(),
+        rl.get_mut(0).unwrap(),
+        rr.get_mut(2).unwrap(),
+    ];
+    for r in refs {
+        *r *= 2;
+    }
+    ll.reverse();
+    rr.swap(0, 2);
+    assert_eq!(data, [2, 14, 4, 3, 20, 14, 6, 5]);
+
+    let mut a = [1, 2, 3];
+    let mut b = [4, 5];
+    let mut chained = (&mut a).chain(&mut b);
+    let (mut left, mut right) = chained.split_mut(1).unwrap();
+    let x = left.get_mut(1).unwrap();
+    let y = right.get_mut(2).unwrap();
+    core::mem::swap(x, y);
+    left.reverse();
+    right.fill(0);
+    assert_eq!((a, b), ([5, 1, 0], [0, 0]));
+
+    let mut data = [1, 2, 3, 4, 5];
+    let mut reversed = (&mut data).rev();
+    let [mut x, mut y] = reversed.split_many_mut([2, 5]).unwrap();
+    let first = x.get_mut(0).unwrap();
+    let last = y.get_mut(2).unwrap();
+    core::mem::swap(first, last);
+    y.reverse();
+    assert_eq!(data, [3, 2, 5, 4, 1]);
+
+    let mut data = [1, 2, 3, 4, 5, 6];
+    let [mut even, mut odd] = data.deinterleave::<2>();
+    let (mut lo, mut hi) = even.split_mut(0).unwrap();
+    let refs = [
+        lo.get_mut(0).unwrap(),
+        hi.get_mut(1).unwrap(),
+        odd.get_mut(2).unwrap(),
+    ];
+    for r in refs {
+        *r = -*r;
+    }
+    assert_eq!(data, [-1, 2, 3, 4, -5, -6]);
+}
+
+// Also meant for Miri: each adapter hands out items through its own handle,
+// which mustn't reborrow the parts of the parent the other half is using, nor
+// the item itself while it's shared.
+#[test]
+fn split_mut_adapters() {
+    fn swap_ends<S: SliceRawMut<Output = i32>>(mut slice: S) {
+        let (mut left, mut right) = slice.split_mut(0).unwrap();
+        let (x, y) = (left.get(0).unwrap(), left.get(0).unwrap());
+        assert_eq!(x, y);
+
+        let a = left.get_mut(0).unwrap();
+        let b = right.get_mut(right.len() - 1).unwrap();
+        core::mem::swap(a, b);
+        *a = -*a;
+        *b = -*b;
+    }
+
+    let mut data = [[1, 2], [3, 4]];
+    swap_ends((&mut data).flatten_uniform(2));
+    assert_eq!(data, [[-4, 2], [3, -1]]);
+
+    let (mut a, mut b) = ([1, 2], [3]);
+    swap_ends(crate::ChainMany::from([&mut a[..], &mut b]));
+    assert_eq!((a, b), ([-3, 2], [-1]));
+
+    let (mut a, mut b) = ([1, 2], [3, 4]);
+    swap_ends((&mut a).interleave(&mut b));
+    assert_eq!((a, b), ([-4, 2], [3, -1]));
+
+    let (mut a, mut b) = ([1], [2, 3]);
+    swap_ends((&mut a).interleave_longest(&mut b));
+    assert_eq!((a, b), ([-3], [2, -1]));
+
+    let (mut a, mut b) = ([1, 2], [3]);
+    swap_ends((&mut a).interleave_ratio(&mut b, 2, 1));
+    assert_eq!((a, b), ([-3, 2], [-1]));
+
+    let (mut a, mut b) = ([1, 2], [3, 4]);
+    swap_ends(crate::interleave_n([&mut a, &mut b]));
+    assert_eq!((a, b), ([-4, 2], [3, -1]));
+
+    let mut pairs = [(1, 'a'), (2, 'b'), (3, 'c')];
+    swap_ends(crate::UnzipLeft(&mut pairs));
+    assert_eq!(pairs, [(-3, 'a'), (2, 'b'), (-1, 'c')]);
+    let (keys, _) = pairs.unzip_mut();
+    swap_ends(keys);
+    assert_eq!(pairs, [(1, 'a'), (2, 'b'), (3, 'c')]);
+
+    let mut data = [1, 2, 3, 4, 5];
+    swap_ends((&mut data).rotated_left(1).step_by(2));
+    swap_ends((&mut data).slice(1..4).unwrap());
+    swap_ends((&mut data).permute([4, 3, 2, 1, 0]).unwrap());
+    assert_eq!(data, [-5, -4, 3, 1, 2]);
+
+    #[cfg(feature = "alloc")]
+    {
+        let mut rows = vec![vec![1, 2], vec![], vec![3]];
+        swap_ends((&mut rows).flatten());
+        assert_eq!(rows, [vec![-3, 2], vec![], vec![-1]]);
+    }
+}
+
+#[test]
+fn split_mut_threads() {
+    let mut data = [1, 2, 3, 4, 5, 6, 7];
+    let (mut left, mut right) = data.split_mut(2).unwrap();
+    std::thread::scope(|scope| {
+        scope.spawn(|| left.fill(0));
+        scope.spawn(|| right.reverse());
+    });
+    assert_eq!(data, [0, 0, 0, 7, 6, 5, 4]);
+
+    #[cfg(feature = "std")]
+    {
+        let mut data: Vec<_> = (0..10).collect();
+        let mut reversed = (&mut data).rev();
+        crate::scoped_chunks_mut(&mut reversed, 3, |mut chunk| {
+            for i in 0..chunk.len() {
+                chunk[i] *= 10;
+            }
+            chunk.reverse();
+        });
+        assert_eq!(data, [0, 30, 20, 10, 60, 50, 40, 90, 80, 70]);
+
+        let mut empty: [i32; 0] = [];
+        crate::scoped_chunks_mut(&mut empty, 1, |_| unreachable!());
+
+        // many chunks still only use a bounded number of threads
+        let threads = std::sync::Mutex::new(std::collections::HashSet::new());
+        let mut data = [0; 100];
+        crate::scoped_chunks_mut(&mut data, 1, |mut chunk| {
+            chunk[0] = 1;
+            threads.lock().unwrap().insert(std::thread::current().id());
+        });
+        assert_eq!(data, [1; 100]);
+        let available = std::thread::available_parallelism().map_or(1, |n| n.get());
+        assert!(threads.into_inner().unwrap().len() <= available);
+    }
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn par_iter() {
+    use rayon::prelude::*;
+
+    let slice = Slice::chain(0..500, 500..1000);
+    let collected: Vec<_> = slice.par_iter_owned().with_max_len(7).collect();
+    assert_eq!(collected, (0..1000).collect::<Vec<_>>());
+
+    let data: Vec<_> = (0..1000).collect();
+    let reversed = (&data).rev();
+    let sum: usize = reversed
+        .par_iter_borrowed()
+        .with_max_len(3)
+        .enumerate()
+        .map(|(i, &x)| i + x)
+        .sum();
+    assert_eq!(sum, 999 * 1000);
+
+    let mut data: Vec<_> = (0..10).collect();
+    let mut stepped = (&mut data).step_by(2);
+    let chunks = stepped.par_split_chunks_mut(2);
+    assert_eq!(IndexedParallelIterator::len(&chunks), 3);
+    chunks
+        .with_max_len(1)
+        .rev()
+        .enumerate()
+        .for_each(|(i, mut chunk)| chunk.fill(i));
+    assert_eq!(data, [2, 1, 2, 3, 1, 5, 1, 7, 0, 9]);
+
+    let mut empty: [i32; 0] = [];
+    assert_eq!(empty.par_split_chunks_mut(1).count(), 0);
+
+    // rayon's own methods still resolve with this crate's traits in scope
+    let mut data: Vec<_> = (0..10).collect();
+    data.par_chunks_mut(3).for_each(|chunk| chunk.reverse());
+    assert_eq!(data.par_iter().sum::<i32>(), 45);
+    assert_eq!(data[..3], [2, 1, 0]);
+}
@@ -0,0 +1,244 @@
+use core::marker::PhantomData;
+
+use crate::{RawSlice, Slice, SliceBorrowed, SliceMut, SliceRawMut, Unique};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<A, B> Sealed for (A, B) {}
+}
+
+/// A tuple of two items.
+///
+/// Unzipping goes through this rather than matching on `(A, B)` directly, so
+/// that borrowing one side doesn't require the other to outlive the borrow.
+///
+/// This trait is sealed, and only implemented for `(A, B)`.
+pub trait Pair: sealed::Sealed {
+    /// The first item.
+    type Left;
+    /// The second item.
+    type Right;
+
+    /// Borrows both items.
+    fn as_refs(&self) -> (&Self::Left, &Self::Right);
+
+    /// Mutably borrows both items.
+    fn as_muts(&mut self) -> (&mut Self::Left, &mut Self::Right);
+
+    /// Returns pointers to both items, without borrowing either.
+    ///
+    /// # Safety
+    ///
+    /// `this` must point to a valid pair.
+    unsafe fn as_ptrs(this: *mut Self) -> (*mut Self::Left, *mut Self::Right);
+}
+
+impl<A, B> Pair for (A, B) {
+    type Left = A;
+    type Right = B;
+
+    fn as_refs(&self) -> (&A, &B) {
+        (&self.0, &self.1)
+    }
+
+    fn as_muts(&mut self) -> (&mut A, &mut B) {
+        (&mut self.0, &mut self.1)
+    }
+
+    unsafe fn as_ptrs(this: *mut Self) -> (*mut A, *mut B) {
+        // SAFETY: guaranteed by the caller
+        unsafe { (&raw mut (*this).0, &raw mut (*this).1) }
+    }
+}
+
+macro_rules! unzip {
+    ($side:ident, $field:tt, $doc:literal) => {
+        paste::paste! {
+            #[doc = "A projection onto the " $doc " item of each pair in a slice; see [`SliceBorrowed::unzip`]."]
+            #[derive(Clone, Copy, Hash)]
+            pub struct [<Unzip $side>]<S>(pub S);
+
+            impl<S> Slice for [<Unzip $side>]<S>
+            where
+                S: SliceBorrowed,
+                S::Output: Pair,
+            {
+                type Output = <S::Output as Pair>::$side;
+
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+
+                fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+                    Some(f(self.get(index)?))
+                }
+            }
+
+            impl<S> SliceBorrowed for [<Unzip $side>]<S>
+            where
+                S: SliceBorrowed,
+                S::Output: Pair,
+            {
+                fn get(&self, index: usize) -> Option<&Self::Output> {
+                    Some(self.0.get(index)?.as_refs().$field)
+                }
+            }
+
+            impl<S> SliceMut for [<Unzip $side>]<S>
+            where
+                S: SliceBorrowed + SliceMut,
+                S::Output: Pair,
+            {
+                fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+                    Some(self.0.get_mut(index)?.as_muts().$field)
+                }
+            }
+
+            // SAFETY: the underlying slice is `Unique`, and distinct pairs have
+            // distinct fields
+            unsafe impl<S> Unique for [<Unzip $side>]<S> where S: Unique {}
+
+            // SAFETY: the underlying slice is only reached through its handle
+            unsafe impl<S> SliceRawMut for [<Unzip $side>]<S>
+            where
+                S: SliceBorrowed + SliceRawMut,
+                S::Output: Pair,
+            {
+                type Raw = [<Unzip $side>]<S::Raw>;
+
+                unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+                    // SAFETY: guaranteed by the caller
+                    unsafe { [<Unzip $side>](S::raw_mut(&raw mut (*this).0)) }
+                }
+            }
+
+            // SAFETY: only the field is projected from the pair, without
+            // borrowing it
+            unsafe impl<R> RawSlice for [<Unzip $side>]<R>
+            where
+                R: RawSlice,
+                R::Output: Pair,
+            {
+                type Output = <R::Output as Pair>::$side;
+
+                unsafe fn len(&self) -> usize {
+                    // SAFETY: guaranteed by the caller
+                    unsafe { self.0.len() }
+                }
+
+                unsafe fn get_mut(&self, index: usize) -> Option<*mut Self::Output> {
+                    // SAFETY: guaranteed by the caller
+                    unsafe { Some(Pair::as_ptrs(self.0.get_mut(index)?).$field) }
+                }
+            }
+
+            #[doc = "A mutable view of the " $doc " item of each pair in a slice; see [`SliceMut::unzip_mut`]."]
+            pub struct [<Unzip $side Mut>]<'a, S: SliceRawMut + ?Sized> {
+                data: [<Unzip $side>]<S::Raw>,
+                _lifetime: PhantomData<&'a mut S>,
+            }
+
+            impl<'a, S> [<Unzip $side Mut>]<'a, S>
+            where
+                S: SliceRawMut + ?Sized,
+                S::Output: Pair,
+            {
+                /// Creates a view of one side of `data`.
+                ///
+                /// # Safety
+                ///
+                /// `data` must be a handle to a slice borrowed for `'a`, and
+                /// nothing else may access this side of its pairs during `'a`.
+                pub(crate) unsafe fn new(data: S::Raw) -> Self {
+                    Self {
+                        data: [<Unzip $side>](data),
+                        _lifetime: PhantomData,
+                    }
+                }
+            }
+
+            impl<S> Slice for [<Unzip $side Mut>]<'_, S>
+            where
+                S: SliceRawMut + ?Sized,
+                S::Output: Pair,
+            {
+                type Output = <S::Output as Pair>::$side;
+
+                fn len(&self) -> usize {
+                    // SAFETY: the slice is borrowed for as long as the view
+                    unsafe { self.data.len() }
+                }
+
+                fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+                    Some(f(self.get(index)?))
+                }
+            }
+
+            impl<S> SliceBorrowed for [<Unzip $side Mut>]<'_, S>
+            where
+                S: SliceRawMut + ?Sized,
+                S::Output: Pair,
+            {
+                fn get(&self, index: usize) -> Option<&Self::Output> {
+                    // SAFETY: only the field belonging to this side is
+                    // borrowed, and only through `self`
+                    unsafe { Some(&*self.data.get_mut(index)?) }
+                }
+            }
+
+            impl<S> SliceMut for [<Unzip $side Mut>]<'_, S>
+            where
+                S: SliceRawMut + ?Sized,
+                S::Output: Pair,
+            {
+                fn get_mut(&mut self, index: usize) -> Option<&mut Self::Output> {
+                    // SAFETY: as above; the other side never touches this
+                    // field, so the borrows can't overlap
+                    unsafe { Some(&mut *self.data.get_mut(index)?) }
+                }
+            }
+
+            // SAFETY: the underlying slice is `Unique`, and distinct pairs have
+            // distinct fields
+            unsafe impl<S> Unique for [<Unzip $side Mut>]<'_, S> where S: SliceRawMut + ?Sized {}
+
+            // SAFETY: the handle is only copied
+            unsafe impl<S> SliceRawMut for [<Unzip $side Mut>]<'_, S>
+            where
+                S: SliceRawMut + ?Sized,
+                S::Output: Pair,
+            {
+                type Raw = [<Unzip $side>]<S::Raw>;
+
+                unsafe fn raw_mut(this: *mut Self) -> Self::Raw {
+                    // SAFETY: guaranteed by the caller
+                    unsafe { (*this).data }
+                }
+            }
+
+            // SAFETY: a view only hands out its own side of each pair, and
+            // only reads the slice itself, so the other side may do the same
+            // from another thread
+            unsafe impl<S> Send for [<Unzip $side Mut>]<'_, S>
+            where
+                S: SliceRawMut + Sync + ?Sized,
+                S::Output: Pair,
+                <S::Output as Pair>::$side: Send,
+            {
+            }
+
+            // SAFETY: see above; a shared view only hands out shared items
+            unsafe impl<S> Sync for [<Unzip $side Mut>]<'_, S>
+            where
+                S: SliceRawMut + Sync + ?Sized,
+                S::Output: Pair,
+                <S::Output as Pair>::$side: Sync,
+            {
+            }
+        }
+    };
+}
+
+unzip!(Left, 0, "first");
+unzip!(Right, 1, "second");
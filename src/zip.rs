@@ -1,10 +1,14 @@
-use crate::{Slice, SliceOwned, Unique};
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{IterOwned, RawSlice, Slice, SliceBorrowed, SliceMut, SliceOwned, SliceRawMut, Unique};
 
 /// Two slices zipped together; see [`SliceOwned::zip`].
 #[derive(Clone, Copy, Hash)]
 pub struct Zip<S1, S2>(pub S1, pub S2);
 
-// TODO: can this be less strict?
+// `get_with` has to hand out a reference to a pair, which means owning both
+// items; borrowed and mutable slices are covered by `ZipBorrowed` and `ZipMut`
 impl<S1, S2> Slice for Zip<S1, S2>
 where
     S1: SliceOwned,
@@ -38,3 +42,307 @@ where
     S2: Unique,
 {
 }
+
+macro_rules! zip_n {
+    ($name:ident, $count:literal; $($s:ident $idx:tt),*) => {
+        #[doc = concat!($count, " slices zipped together; see [`zip!`](crate::zip!).")]
+        #[derive(Clone, Copy, Hash)]
+        pub struct $name<$($s),*>($(pub $s),*);
+
+        impl<$($s),*> Slice for $name<$($s),*>
+        where
+            $($s: SliceOwned,)*
+        {
+            type Output = ($($s::Output,)*);
+
+            fn len(&self) -> usize {
+                let len = usize::MAX;
+                $(let len = len.min(self.$idx.len());)*
+                len
+            }
+
+            fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+                Some(f(&self.get_owned(index)?))
+            }
+        }
+
+        impl<$($s),*> SliceOwned for $name<$($s),*>
+        where
+            $($s: SliceOwned,)*
+        {
+            fn get_owned(&self, index: usize) -> Option<Self::Output> {
+                Some(($(self.$idx.get_owned(index)?,)*))
+            }
+        }
+
+        // SAFETY: all underlying slices are `Unique`
+        unsafe impl<$($s),*> Unique for $name<$($s),*>
+        where
+            $($s: Unique,)*
+        {
+        }
+
+        impl<$($s),*> fmt::Debug for $name<$($s),*>
+        where
+            $($s: SliceOwned,)*
+            $($s::Output: fmt::Debug,)*
+        {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut list = f.debug_list();
+                for i in 0..self.len() {
+                    self.get_with(i, &mut |x| {
+                        list.entry(x);
+                    });
+                }
+                list.finish()
+            }
+        }
+
+        impl<O, U, $($s),*> PartialEq<O> for $name<$($s),*>
+        where
+            O: Slice<Output = U>,
+            U: PartialEq<($($s::Output,)*)>,
+            $($s: SliceOwned,)*
+        {
+            fn eq(&self, other: &O) -> bool {
+                if self.len() != other.len() {
+                    false
+                } else {
+                    for i in 0..self.len() {
+                        if other
+                            .get_with(i, &mut |x| x != &self.get_owned(i).unwrap())
+                            .unwrap_or(true)
+                        {
+                            return false;
+                        }
+                    }
+
+                    true
+                }
+            }
+        }
+    };
+}
+
+zip_n!(Zip3, "Three"; S1 0, S2 1, S3 2);
+zip_n!(Zip4, "Four"; S1 0, S2 1, S3 2, S4 3);
+
+/// Two borrowed slices zipped together, where indexing returns a tuple of
+/// references; see [`SliceBorrowed::zip`].
+#[derive(Clone, Copy)]
+pub struct ZipBorrowed<'a, S1: ?Sized, S2: ?Sized>(pub &'a S1, pub &'a S2);
+
+impl<'a, S1, S2> Slice for ZipBorrowed<'a, S1, S2>
+where
+    S1: SliceBorrowed + ?Sized,
+    S2: SliceBorrowed + ?Sized,
+{
+    type Output = (&'a S1::Output, &'a S2::Output);
+
+    fn len(&self) -> usize {
+        self.0.len().min(self.1.len())
+    }
+
+    fn get_with<W: FnMut(&Self::Output) -> R, R>(&self, index: usize, f: &mut W) -> Option<R> {
+        Some(f(&self.get_owned(index)?))
+    }
+}
+
+impl<S1, S2> SliceOwned for ZipBorrowed<'_, S1, S2>
+where
+    S1: SliceBorrowed + ?Sized,
+    S2: SliceBorrowed + ?Sized,
+{
+    fn get_owned(&self, index: usize) -> Option<Self::Output> {
+        Some((self.0.get(index)?, self.1.get(index)?))
+    }
+}
+
+/// Two mutable slices zipped together, so they can be updated in lockstep;
+/// see [`SliceMut::zip_mut`].
+///
+/// Since a pair of mutable references can't be stored anywhere, this isn't a
+/// [`Slice`] itself; use [`ZipMut::get_mut_pair`] or [`ZipMut::iter_mut`]
+/// instead.
+#[derive(Clone, Copy, Hash)]
+pub struct ZipMut<S1, S2>(pub S1, pub S2);
+
+impl<S1, S2> ZipMut<S1, S2>
+where
+    S1: SliceMut,
+    S2: SliceMut,
+{
+    /// Returns the length of the shorter slice.
+    pub fn len(&self) -> usize {
+        self.0.len().min(self.1.len())
+    }
+
+    /// Returns `true` if either slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns references to the items at `index` in both slices, or `None`
+    /// if it's out-of-bounds.
+    pub fn get_pair(&self, index: usize) -> Option<(&S1::Output, &S2::Output)>
+    where
+        S1: SliceBorrowed,
+        S2: SliceBorrowed,
+    {
+        Some((self.0.get(index)?, self.1.get(index)?))
+    }
+
+    /// Returns mutable references to the items at `index` in both slices, or
+    /// `None` if it's out-of-bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceMut;
+    /// let mut xs = [1, 2, 3];
+    /// let mut ys = [4, 5];
+    /// let mut zipped = (&mut xs).zip_mut(&mut ys);
+    ///
+    /// let (x, y) = zipped.get_mut_pair(1).unwrap();
+    /// core::mem::swap(x, y);
+    /// assert!(zipped.get_mut_pair(2).is_none());
+    ///
+    /// assert_eq!(xs, [1, 5, 3]);
+    /// assert_eq!(ys, [4, 2]);
+    /// ```
+    pub fn get_mut_pair(&mut self, index: usize) -> Option<(&mut S1::Output, &mut S2::Output)> {
+        if index >= self.len() {
+            return None;
+        }
+
+        Some((self.0.get_mut(index)?, self.1.get_mut(index)?))
+    }
+
+    /// Creates an iterator over references to each pair of items.
+    pub fn iter(&self) -> IterOwned<ZipBorrowed<'_, S1, S2>>
+    where
+        S1: SliceBorrowed,
+        S2: SliceBorrowed,
+    {
+        IterOwned::new(ZipBorrowed(&self.0, &self.1))
+    }
+
+    /// Creates an iterator over mutable references to each pair of items.
+    ///
+    /// To avoid aliasing, requires both slices to be [`SliceRawMut`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use slice_utils::SliceMut;
+    /// let mut positions = [0, 10, 20];
+    /// let mut velocities = [1, -1, 2];
+    ///
+    /// for (p, v) in (&mut positions).zip_mut(&mut velocities).iter_mut() {
+    ///     *p += *v;
+    ///     *v = 0;
+    /// }
+    ///
+    /// assert_eq!(positions, [1, 9, 22]);
+    /// assert_eq!(velocities, [0, 0, 0]);
+    /// ```
+    pub fn iter_mut(&mut self) -> ZipIterMut<'_, S1, S2>
+    where
+        S1: SliceRawMut,
+        S2: SliceRawMut,
+    {
+        let end = self.len();
+        // SAFETY: `self` is borrowed for as long as the iterator lives, and is
+        // only reached through the handles until then
+        let data = unsafe { (S1::raw_mut(&mut self.0), S2::raw_mut(&mut self.1)) };
+
+        ZipIterMut {
+            data,
+            start: 0,
+            end,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+// SAFETY: both underlying slices are `Unique`
+unsafe impl<S1, S2> Unique for ZipMut<S1, S2>
+where
+    S1: Unique,
+    S2: Unique,
+{
+}
+
+/// An iterator over mutable references to pairs of items; see
+/// [`ZipMut::iter_mut`].
+///
+/// Only [`RawSlice`] handles to the slices are kept, captured once on
+/// creation, so yielding a pair never invalidates the ones before it.
+pub struct ZipIterMut<'a, S1: SliceRawMut, S2: SliceRawMut> {
+    data: (S1::Raw, S2::Raw),
+    start: usize,
+    end: usize,
+
+    _lifetime: PhantomData<&'a mut ZipMut<S1, S2>>,
+}
+
+impl<'a, S1, S2> ZipIterMut<'a, S1, S2>
+where
+    S1: SliceRawMut,
+    S2: SliceRawMut,
+{
+    fn pair(&self, index: usize) -> Option<(&'a mut S1::Output, &'a mut S2::Output)> {
+        // SAFETY: the slices are borrowed for `'a`, and are only reached
+        // through the handles until then; both are `Unique`, and every index
+        // is yielded at most once, so none of the returned references alias
+        unsafe {
+            let (a, b) = (self.data.0.get_mut(index)?, self.data.1.get_mut(index)?);
+            Some((&mut *a, &mut *b))
+        }
+    }
+}
+
+impl<'a, S1, S2> Iterator for ZipIterMut<'a, S1, S2>
+where
+    S1: SliceRawMut,
+    S2: SliceRawMut,
+{
+    type Item = (&'a mut S1::Output, &'a mut S2::Output);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else {
+            let x = self.pair(self.start)?;
+            self.start += 1;
+            Some(x)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<S1, S2> DoubleEndedIterator for ZipIterMut<'_, S1, S2>
+where
+    S1: SliceRawMut,
+    S2: SliceRawMut,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            self.pair(self.end)
+        }
+    }
+}
+
+impl<S1, S2> ExactSizeIterator for ZipIterMut<'_, S1, S2>
+where
+    S1: SliceRawMut,
+    S2: SliceRawMut,
+{
+}